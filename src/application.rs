@@ -29,6 +29,7 @@ use gettextrs::gettext;
 use gtk::{gio, glib};
 
 use crate::TabelaWindow;
+use crate::cli;
 use crate::config::VERSION;
 
 mod imp {
@@ -49,7 +50,13 @@ mod imp {
             self.parent_constructed();
             let obj = self.obj();
             obj.setup_gactions();
+            cli::add_options(&*obj);
             obj.set_accels_for_action("app.quit", &["<primary>q"]);
+            obj.set_accels_for_action("win.open", &["<primary>o"]);
+            obj.set_accels_for_action("win.save", &["<primary>s"]);
+            obj.set_accels_for_action("win.save-as", &["<primary><shift>s"]);
+            obj.set_accels_for_action("win.copy-output", &["<primary><shift>c"]);
+            obj.set_accels_for_action("win.paste-html", &["<primary><shift>v"]);
         }
     }
 
@@ -59,16 +66,25 @@ mod imp {
         // tries to launch a "second instance" of the application. When they try
         // to do that, we'll just present any existing window.
         fn activate(&self) {
-            let application = self.obj();
-            // Get the current window or create one if necessary
-            let window = application.active_window().unwrap_or_else(|| {
-                let window = TabelaWindow::new(&*application);
-                window.upcast()
-            });
-
             // Ask the window manager/compositor to present the window
+            self.obj().window().present();
+        }
+
+        // Called instead of activate when files are passed on the command line
+        // or opened with Tabëla from a file manager: only one can be shown.
+        fn open(&self, files: &[gio::File], _hint: &str) {
+            let window = self.obj().window();
+
+            if let Some(file) = files.first() {
+                window.open_file(file);
+            }
+
             window.present();
         }
+
+        fn handle_local_options(&self, options: &glib::VariantDict) -> glib::ExitCode {
+            cli::run(options).unwrap_or_else(|| self.parent_handle_local_options(options))
+        }
     }
 
     impl GtkApplicationImpl for TabelaApplication {}
@@ -89,6 +105,13 @@ impl TabelaApplication {
             .build()
     }
 
+    /// Gets the current window or creates one if necessary.
+    fn window(&self) -> TabelaWindow {
+        self.active_window()
+            .and_downcast::<TabelaWindow>()
+            .unwrap_or_else(|| TabelaWindow::new(self))
+    }
+
     fn setup_gactions(&self) {
         let quit_action = gio::ActionEntry::builder("quit")
             .activate(move |app: &Self, _, _| app.quit())
@@ -0,0 +1,358 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+//! Headless conversions, for using Tabëla from scripts:
+//! `tabela --to markdown --separator tab --titles < in.tsv > out.md`
+
+use gettextrs::gettext;
+use gtk::prelude::*;
+use gtk::{gio, glib};
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+use crate::presets::{
+    BOX_STYLES, CSV_DELIMITERS, FORMATS, FormatOptions, LATEX_RULES, QUOTINGS, SEPARATORS,
+    SQL_DIALECTS, Separator,
+};
+use tabela_core::{JsonLayout, JsonValues, MarkdownEscaping, SqlStatements};
+
+/// The input couldn't be read, or the output couldn't be written
+const EXIT_IO_ERROR: i32 = 1;
+/// The options make no sense
+const EXIT_USAGE: i32 = 2;
+
+/// Adds the command line options of headless conversions.
+pub fn add_options(app: &impl IsA<gio::Application>) {
+    app.add_main_option(
+        "to",
+        glib::Char::from(b't'),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::String,
+        &gettext("Convert the input to FORMAT on standard output, without opening a window"),
+        Some(&gettext("FORMAT")),
+    );
+    app.add_main_option(
+        "separator",
+        glib::Char::from(b's'),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::String,
+        &gettext("How the input is separated: auto (the default), tab, comma, semicolon, pipe, aligned, markdown, html, or any other text"),
+        Some(&gettext("SEPARATOR")),
+    );
+    app.add_main_option(
+        "titles",
+        glib::Char::from(b'T'),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::None,
+        &gettext("Use the first row as titles"),
+        None,
+    );
+    app.add_main_option(
+        "keep-quotes",
+        glib::Char::from(b'q'),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::None,
+        &gettext("Keep the quotes around cells"),
+        None,
+    );
+    app.add_main_option(
+        "input",
+        glib::Char::from(b'i'),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::Filename,
+        &gettext("Read FILE instead of standard input"),
+        Some(&gettext("FILE")),
+    );
+    app.add_main_option(
+        "keep-markup",
+        glib::Char::from(0),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::None,
+        &gettext("Markdown: don't escape the characters which would style the text"),
+        None,
+    );
+    app.add_main_option(
+        "delimiter",
+        glib::Char::from(0),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::String,
+        &gettext("CSV: separate cells with comma (the default), semicolon or tab"),
+        Some(&gettext("DELIMITER")),
+    );
+    app.add_main_option(
+        "quoting",
+        glib::Char::from(0),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::String,
+        &gettext("CSV: quote cells when needed (minimal, the default), all or nonnumeric"),
+        Some(&gettext("QUOTING")),
+    );
+    app.add_main_option(
+        "latex-rules",
+        glib::Char::from(0),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::String,
+        &gettext("LaTeX: rule the table with hline (the default) or booktabs"),
+        Some(&gettext("RULES")),
+    );
+    app.add_main_option(
+        "compact",
+        glib::Char::from(0),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::None,
+        &gettext("JSON: write everything on one line"),
+        None,
+    );
+    app.add_main_option(
+        "strings-only",
+        glib::Char::from(0),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::None,
+        &gettext("JSON: write every cell as a string"),
+        None,
+    );
+    app.add_main_option(
+        "dialect",
+        glib::Char::from(0),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::String,
+        &gettext("SQL: write SQL for postgresql (the default), mysql or sqlite"),
+        Some(&gettext("DIALECT")),
+    );
+    app.add_main_option(
+        "table-name",
+        glib::Char::from(0),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::String,
+        &gettext("SQL: the name of the table (data by default)"),
+        Some(&gettext("NAME")),
+    );
+    app.add_main_option(
+        "insert-only",
+        glib::Char::from(0),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::None,
+        &gettext("SQL: only write the INSERT statements, without CREATE TABLE"),
+        None,
+    );
+    app.add_main_option(
+        "border",
+        glib::Char::from(0),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::String,
+        &gettext(
+            "Plain text: draw single (the default), double, rounded, ascii or borderless lines",
+        ),
+        Some(&gettext("BORDER")),
+    );
+    app.add_main_option(
+        "list-formats",
+        glib::Char::from(0),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::None,
+        &gettext("List the formats accepted by --to"),
+        None,
+    );
+}
+
+/// Runs a headless conversion if the options ask for one, returning the exit
+/// code; returns `None` when the application should start as usual.
+pub fn run(options: &glib::VariantDict) -> Option<glib::ExitCode> {
+    if options.contains("list-formats") {
+        for (name, _, label) in FORMATS {
+            println!("{name:16}{}", gettext(*label));
+        }
+        return Some(glib::ExitCode::SUCCESS);
+    }
+
+    let Ok(Some(format)) = options.lookup::<String>("to") else {
+        if [
+            "separator",
+            "titles",
+            "keep-quotes",
+            "input",
+            "keep-markup",
+            "delimiter",
+            "quoting",
+            "latex-rules",
+            "compact",
+            "strings-only",
+            "dialect",
+            "table-name",
+            "insert-only",
+            "border",
+        ]
+        .iter()
+        .any(|o| options.contains(o))
+        {
+            return Some(fail(
+                EXIT_USAGE,
+                &gettext("--to is required to convert without a window"),
+            ));
+        }
+        return None;
+    };
+
+    Some(convert(options, &format).unwrap_or_else(|(code, message)| fail(code, &message)))
+}
+
+fn convert(options: &glib::VariantDict, format: &str) -> Result<glib::ExitCode, (i32, String)> {
+    let Some((_, format, _)) = FORMATS.iter().find(|f| f.0 == format) else {
+        return Err((
+            EXIT_USAGE,
+            gettext("Unknown format “{}”, see --list-formats").replace("{}", format),
+        ));
+    };
+    let mut format_options = format_options(options)?;
+
+    let separator = options
+        .lookup::<String>("separator")
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| "auto".to_string());
+
+    // Names of input modes, or else the separator itself
+    let mode = SEPARATORS
+        .iter()
+        .find(|s| s.0 == separator && s.1 != Separator::Custom)
+        .map(|s| s.1)
+        .unwrap_or(Separator::Custom);
+
+    format_options.adapt_to(mode);
+    let formatter = format.formatter(&format_options);
+
+    let input = options.lookup::<PathBuf>("input").ok().flatten();
+    let text = read_input(input.as_ref()).map_err(|message| (EXIT_IO_ERROR, message))?;
+
+    let table = mode.read(
+        &text,
+        &separator,
+        options.contains("titles"),
+        !options.contains("keep-quotes"),
+    );
+    let result = formatter.format(table);
+
+    std::io::stdout()
+        .lock()
+        .write_all(result.as_bytes())
+        .map_err(|err| (EXIT_IO_ERROR, err.to_string()))?;
+
+    Ok(glib::ExitCode::SUCCESS)
+}
+
+fn format_options(options: &glib::VariantDict) -> Result<FormatOptions, (i32, String)> {
+    let mut format_options = FormatOptions::default();
+
+    if options.contains("keep-markup") {
+        format_options.markdown_escaping = MarkdownEscaping::PipesOnly;
+    }
+    if options.contains("compact") {
+        format_options.json_layout = JsonLayout::Compact;
+    }
+    if options.contains("strings-only") {
+        format_options.json_values = JsonValues::Strings;
+    }
+    if options.contains("insert-only") {
+        format_options.sql_statements = SqlStatements::InsertOnly;
+    }
+    if let Ok(Some(name)) = options.lookup::<String>("table-name") {
+        format_options.table_name = name;
+    }
+
+    choose(
+        options,
+        "delimiter",
+        CSV_DELIMITERS,
+        &mut format_options.csv_delimiter,
+    )?;
+    choose(options, "quoting", QUOTINGS, &mut format_options.quoting)?;
+    choose(
+        options,
+        "latex-rules",
+        LATEX_RULES,
+        &mut format_options.latex_rules,
+    )?;
+    choose(
+        options,
+        "dialect",
+        SQL_DIALECTS,
+        &mut format_options.sql_dialect,
+    )?;
+    choose(options, "border", BOX_STYLES, &mut format_options.box_style)?;
+
+    Ok(format_options)
+}
+
+/// Sets `value` to the choice named by an option, if given.
+fn choose<T: Copy>(
+    options: &glib::VariantDict,
+    option: &str,
+    choices: &[(&str, T, &str)],
+    value: &mut T,
+) -> Result<(), (i32, String)> {
+    let Ok(Some(name)) = options.lookup::<String>(option) else {
+        return Ok(());
+    };
+
+    match choices.iter().find(|c| c.0 == name) {
+        Some(choice) => {
+            *value = choice.1;
+            Ok(())
+        }
+        None => {
+            let names = choices.iter().map(|c| c.0).collect::<Vec<_>>().join(", ");
+            Err((
+                EXIT_USAGE,
+                gettext("Unknown value “{}” for --{}, expected one of: {}")
+                    .replacen("{}", &name, 1)
+                    .replacen("{}", option, 1)
+                    .replacen("{}", &names, 1),
+            ))
+        }
+    }
+}
+
+fn read_input(path: Option<&PathBuf>) -> Result<String, String> {
+    let mut bytes = Vec::new();
+
+    let read = match path {
+        Some(path) => std::fs::File::open(path).and_then(|mut f| f.read_to_end(&mut bytes)),
+        None => std::io::stdin().lock().read_to_end(&mut bytes),
+    };
+
+    let name = path
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| gettext("standard input"));
+
+    read.map_err(|err| format!("{name}: {err}"))?;
+
+    String::from_utf8(bytes)
+        .map_err(|_| gettext("{}: the text is not valid UTF-8").replace("{}", &name))
+}
+
+fn fail(code: i32, message: &str) -> glib::ExitCode {
+    eprintln!("tabela: {message}");
+    glib::ExitCode::from(code)
+}
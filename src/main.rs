@@ -24,8 +24,9 @@
  */
 
 mod application;
+mod cli;
 mod config;
-mod formatting;
+mod presets;
 mod window;
 
 use self::application::TabelaApplication;
@@ -43,7 +44,17 @@ pub(crate) const fn translatable(s: &'static str) -> &'static str {
     s
 }
 
+/// Sends the logs of the formatting engine to the GLib log, like the rest of
+/// the application.
+static GLIB_LOGGER: glib::GlibLogger = glib::GlibLogger::new(
+    glib::GlibLoggerFormat::Plain,
+    glib::GlibLoggerDomain::CrateTarget,
+);
+
 fn main() -> glib::ExitCode {
+    log::set_logger(&GLIB_LOGGER).expect("Unable to set the logger");
+    log::set_max_level(log::LevelFilter::Debug);
+
     // Set up gettext translations
     bindtextdomain(GETTEXT_PACKAGE, LOCALEDIR).expect("Unable to bind the text domain");
     bind_textdomain_codeset(GETTEXT_PACKAGE, "UTF-8")
@@ -60,7 +71,7 @@ fn main() -> glib::ExitCode {
     // desktop features such as file opening and single-instance applications.
     let app = TabelaApplication::new(
         "com.mastropaolo.www.tabela",
-        &gio::ApplicationFlags::empty(),
+        &gio::ApplicationFlags::HANDLES_OPEN,
     );
 
     // Run the application. This function will block until the application
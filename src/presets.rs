@@ -0,0 +1,314 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use gtk::gio;
+use gtk::prelude::*;
+use tabela_core::{
+    BoxStyle, CsvFormatter, Formatter, JsonFormatter, JsonLayout, JsonValues, LatexFormatter,
+    LatexRules, MarkdownEscaping, MarkdownFormatter, Quoting, SqlDialect, SqlFormatter,
+    SqlStatements, Table, TextFormatter,
+};
+
+use crate::translatable;
+
+/// An output format; the ones with options take them from [`FormatOptions`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Markdown,
+    Html,
+    Csv,
+    AsciiDoc,
+    RstGrid,
+    RstSimple,
+    Latex,
+    Jira,
+    MediaWiki,
+    Org,
+    Json,
+    Yaml,
+    Toml,
+    Sql,
+    Text,
+}
+
+/// The output formats: the name used on the command line, the format and the
+/// label shown in the format dropdown.
+pub const FORMATS: &[(&str, Format, &str)] = &[
+    ("markdown", Format::Markdown, translatable("Markdown")),
+    ("html", Format::Html, translatable("HTML")),
+    ("csv", Format::Csv, translatable("CSV")),
+    ("asciidoc", Format::AsciiDoc, translatable("AsciiDoc")),
+    (
+        "rst-grid",
+        Format::RstGrid,
+        translatable("reStructuredText (grid)"),
+    ),
+    (
+        "rst-simple",
+        Format::RstSimple,
+        translatable("reStructuredText (simple)"),
+    ),
+    ("latex", Format::Latex, translatable("LaTeX")),
+    ("jira", Format::Jira, translatable("Jira / Confluence")),
+    ("mediawiki", Format::MediaWiki, translatable("MediaWiki")),
+    ("org", Format::Org, translatable("Org mode")),
+    ("json", Format::Json, translatable("JSON")),
+    ("yaml", Format::Yaml, translatable("YAML")),
+    ("toml", Format::Toml, translatable("TOML")),
+    ("sql", Format::Sql, translatable("SQL")),
+    ("text", Format::Text, translatable("Plain text")),
+];
+
+/// The options of the formats which have any.
+#[derive(Clone)]
+pub struct FormatOptions {
+    pub markdown_escaping: MarkdownEscaping,
+    pub csv_delimiter: &'static str,
+    pub quoting: Quoting,
+    pub latex_rules: LatexRules,
+    pub json_layout: JsonLayout,
+    pub json_values: JsonValues,
+    pub sql_dialect: SqlDialect,
+    pub sql_statements: SqlStatements,
+    pub table_name: String,
+    pub box_style: BoxStyle,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            markdown_escaping: MarkdownEscaping::Markup,
+            csv_delimiter: CSV_DELIMITERS[0].1,
+            quoting: QUOTINGS[0].1,
+            latex_rules: LATEX_RULES[0].1,
+            json_layout: JsonLayout::Pretty,
+            json_values: JsonValues::Typed,
+            sql_dialect: SQL_DIALECTS[0].1,
+            sql_statements: SqlStatements::CreateAndInsert,
+            table_name: DEFAULT_TABLE_NAME.to_string(),
+            box_style: BOX_STYLES[0].1,
+        }
+    }
+}
+
+/// The table SQL is written for, unless another name is given.
+pub const DEFAULT_TABLE_NAME: &str = "data";
+
+// The choices of the options with more than two, as name used on the command
+// line, value and label shown in their dropdown.
+
+pub const CSV_DELIMITERS: &[(&str, &str, &str)] = &[
+    ("comma", ",", translatable("Comma")),
+    ("semicolon", ";", translatable("Semicolon")),
+    ("tab", "\t", translatable("TAB")),
+];
+
+pub const QUOTINGS: &[(&str, Quoting, &str)] = &[
+    (
+        "minimal",
+        Quoting::Minimal,
+        translatable("Quote when needed"),
+    ),
+    ("all", Quoting::All, translatable("Quote all")),
+    (
+        "nonnumeric",
+        Quoting::NonNumeric,
+        translatable("Quote non-numeric"),
+    ),
+];
+
+pub const LATEX_RULES: &[(&str, LatexRules, &str)] = &[
+    ("hline", LatexRules::Hline, translatable("Grid lines")),
+    ("booktabs", LatexRules::Booktabs, translatable("booktabs")),
+];
+
+pub const SQL_DIALECTS: &[(&str, SqlDialect, &str)] = &[
+    (
+        "postgresql",
+        SqlDialect::PostgreSql,
+        translatable("PostgreSQL"),
+    ),
+    ("mysql", SqlDialect::MySql, translatable("MySQL")),
+    ("sqlite", SqlDialect::Sqlite, translatable("SQLite")),
+];
+
+pub const BOX_STYLES: &[(&str, BoxStyle, &str)] = &[
+    ("single", BoxStyle::Single, translatable("Single lines")),
+    ("double", BoxStyle::Double, translatable("Double lines")),
+    ("rounded", BoxStyle::Rounded, translatable("Rounded")),
+    ("ascii", BoxStyle::Ascii, translatable("ASCII")),
+    (
+        "borderless",
+        BoxStyle::Borderless,
+        translatable("Borderless"),
+    ),
+];
+
+impl FormatOptions {
+    /// Adjusts the options to the input mode. Cells read from a Markdown table
+    /// are Markdown already, so escaping their markup again would add another
+    /// layer of backslashes at every round trip.
+    pub fn adapt_to(&mut self, input: Separator) {
+        if input == Separator::Markdown {
+            self.markdown_escaping = MarkdownEscaping::PipesOnly;
+        }
+    }
+}
+
+impl Format {
+    /// The formatter writing this format with `options`.
+    pub fn formatter(self, options: &FormatOptions) -> Box<dyn Formatter> {
+        match self {
+            Format::Markdown => Box::new(MarkdownFormatter::new(options.markdown_escaping)),
+            Format::Html => Box::new(tabela_core::HtmlFormatter),
+            Format::Csv => Box::new(CsvFormatter::new(options.csv_delimiter, options.quoting)),
+            Format::AsciiDoc => Box::new(tabela_core::AsciiDocFormatter),
+            Format::RstGrid => Box::new(tabela_core::RstGridFormatter),
+            Format::RstSimple => Box::new(tabela_core::RstSimpleFormatter),
+            Format::Latex => Box::new(LatexFormatter::new(options.latex_rules)),
+            Format::Jira => Box::new(tabela_core::JiraFormatter),
+            Format::MediaWiki => Box::new(tabela_core::MediaWikiFormatter),
+            Format::Org => Box::new(tabela_core::OrgFormatter),
+            Format::Json => Box::new(JsonFormatter::new(options.json_layout, options.json_values)),
+            Format::Yaml => Box::new(tabela_core::YamlFormatter),
+            Format::Toml => Box::new(tabela_core::TomlFormatter),
+            Format::Sql => Box::new(SqlFormatter::new(
+                options.sql_dialect,
+                options.table_name.clone(),
+                options.sql_statements,
+            )),
+            Format::Text => Box::new(TextFormatter::new(options.box_style)),
+        }
+    }
+
+    /// The extension of saved files.
+    pub fn extension(self, options: &FormatOptions) -> &'static str {
+        match self {
+            Format::Markdown => "md",
+            Format::Html => "html",
+            Format::Csv if options.csv_delimiter == "\t" => "tsv",
+            Format::Csv => "csv",
+            Format::AsciiDoc => "adoc",
+            Format::RstGrid | Format::RstSimple => "rst",
+            Format::Latex => "tex",
+            Format::Jira | Format::MediaWiki | Format::Text => "txt",
+            Format::Org => "org",
+            Format::Json => "json",
+            Format::Yaml => "yaml",
+            Format::Toml => "toml",
+            Format::Sql => "sql",
+        }
+    }
+}
+
+/// How the input text is read into a table.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Separator {
+    /// Detect the separator from the text
+    Auto,
+    Fixed(&'static str),
+    /// The separator typed by the user
+    Custom,
+    Aligned,
+    Markdown,
+    Html,
+}
+
+/// The input modes: the name used on the command line, the mode and the label
+/// shown in the separator dropdown.
+pub const SEPARATORS: &[(&str, Separator, &str)] = &[
+    ("auto", Separator::Auto, translatable("Auto")),
+    ("tab", Separator::Fixed("\t"), translatable("TAB")),
+    ("comma", Separator::Fixed(","), translatable("Comma")),
+    (
+        "semicolon",
+        Separator::Fixed(";"),
+        translatable("Semicolon"),
+    ),
+    ("pipe", Separator::Fixed("|"), translatable("Pipe")),
+    ("custom", Separator::Custom, translatable("Custom")),
+    (
+        "aligned",
+        Separator::Aligned,
+        translatable("Aligned columns"),
+    ),
+    (
+        "markdown",
+        Separator::Markdown,
+        translatable("Markdown table"),
+    ),
+    ("html", Separator::Html, translatable("HTML table")),
+];
+
+/// The input modes preselected when opening files, by extension.
+pub const EXTENSIONS: &[(&str, Separator)] = &[
+    // Depending on the locale, spreadsheets separate CSV with commas or semicolons
+    ("csv", Separator::Auto),
+    ("tsv", Separator::Fixed("\t")),
+    ("tab", Separator::Fixed("\t")),
+    ("txt", Separator::Auto),
+    ("md", Separator::Markdown),
+    ("markdown", Separator::Markdown),
+    ("html", Separator::Html),
+    ("htm", Separator::Html),
+];
+
+impl Separator {
+    /// The input mode for a file, by its extension.
+    pub fn for_file(file: &gio::File) -> Option<Self> {
+        let path = file.path().or_else(|| file.basename())?;
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+
+        EXTENSIONS.iter().find(|e| e.0 == extension).map(|e| e.1)
+    }
+
+    /// Reads a table from text, using `custom` as the separator in `Custom`
+    /// mode. A TAB can't be typed in the separator entry, so `\t` stands for it.
+    pub fn read<'a>(
+        self,
+        text: &'a str,
+        custom: &str,
+        has_head: bool,
+        remove_quotes: bool,
+    ) -> Table<'a> {
+        let split = |separator: &str| {
+            Table::with_text_and_separator(text, separator, has_head, remove_quotes)
+        };
+
+        match self {
+            Separator::Fixed(separator) => split(separator),
+            Separator::Custom => split(&custom.replace("\\t", "\t")),
+            Separator::Auto => split(detect_separator(text)),
+            Separator::Aligned => Table::with_aligned_text(text, has_head),
+            Separator::Markdown => Table::with_markdown_text(text, has_head),
+            Separator::Html => Table::with_html_text(text, has_head),
+        }
+    }
+}
+
+/// The separator used by text in `Auto` mode, TAB if none can be detected.
+pub fn detect_separator(text: &str) -> &'static str {
+    tabela_core::detect_separator(text).unwrap_or("\t")
+}
@@ -23,25 +23,18 @@
  * SPDX-License-Identifier: MIT
  */
 
+use adw::prelude::*;
 use adw::subclass::prelude::*;
 use gettextrs::gettext;
 use glib::clone;
-use gtk::prelude::*;
-use gtk::{gio, glib};
+use gtk::{gdk, gio, glib};
+use std::cell::RefCell;
 
-use crate::formatting;
-use crate::translatable;
-
-const FORMATTERS: &[(&dyn formatting::Formatter, &str)] = &[
-    (&formatting::MarkdownFormatter, translatable("Markdown")),
-    (&formatting::HtmlFormatter, translatable("HTML")),
-];
-
-const SEPARATORS: &[(char, &str)] = &[
-    ('\t', translatable("TAB")),
-    (',', translatable("Comma")),
-    (';', translatable("Semicolon")),
-];
+use crate::presets::{
+    self, BOX_STYLES, CSV_DELIMITERS, EXTENSIONS, FORMATS, Format, FormatOptions, LATEX_RULES,
+    QUOTINGS, SEPARATORS, SQL_DIALECTS, Separator,
+};
+use tabela_core::{JsonLayout, JsonValues, MarkdownEscaping, SqlStatements};
 
 mod imp {
     use super::*;
@@ -52,8 +45,44 @@ mod imp {
         #[template_child]
         pub dropdown_separator: TemplateChild<gtk::DropDown>,
         #[template_child]
+        pub label_detected_separator: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub entry_separator: TemplateChild<gtk::Entry>,
+        #[template_child]
         pub dropdown_format: TemplateChild<gtk::DropDown>,
         #[template_child]
+        pub box_markdown_options: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub switch_keep_markup: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub box_csv_options: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub dropdown_csv_delimiter: TemplateChild<gtk::DropDown>,
+        #[template_child]
+        pub dropdown_quoting: TemplateChild<gtk::DropDown>,
+        #[template_child]
+        pub box_latex_options: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub dropdown_latex_rules: TemplateChild<gtk::DropDown>,
+        #[template_child]
+        pub box_json_options: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub switch_json_compact: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub switch_json_strings: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub box_sql_options: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub dropdown_sql_dialect: TemplateChild<gtk::DropDown>,
+        #[template_child]
+        pub entry_table_name: TemplateChild<gtk::Entry>,
+        #[template_child]
+        pub switch_create_table: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub box_text_options: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub dropdown_box_style: TemplateChild<gtk::DropDown>,
+        #[template_child]
         pub switch_titles: TemplateChild<gtk::Switch>,
         #[template_child]
         pub switch_remove_quotes: TemplateChild<gtk::Switch>,
@@ -61,6 +90,12 @@ mod imp {
         pub text_input: TemplateChild<gtk::TextView>,
         #[template_child]
         pub text_output: TemplateChild<gtk::TextView>,
+        #[template_child]
+        pub toast_overlay: TemplateChild<adw::ToastOverlay>,
+        /// Name of the opened file, without extension, to suggest when saving
+        pub input_name: RefCell<Option<String>>,
+        /// Where the output was last saved, with its format and extension
+        pub output_file: RefCell<Option<(gio::File, Format, &'static str)>>,
     }
 
     #[glib::object_subclass]
@@ -82,13 +117,18 @@ mod imp {
         fn constructed(&self) {
             self.parent_constructed();
 
-            let separators = SEPARATORS.iter().map(|s| gettext(s.1)).collect::<Vec<_>>();
-            let separators = gtk::StringList::from_iter(separators);
-            self.dropdown_separator.set_model(Some(&separators));
+            fn set_labels<T>(dropdown: &gtk::DropDown, choices: &[(&str, T, &str)]) {
+                let labels = choices.iter().map(|c| gettext(c.2)).collect::<Vec<_>>();
+                dropdown.set_model(Some(&gtk::StringList::from_iter(labels)));
+            }
 
-            let formatters = FORMATTERS.iter().map(|s| gettext(s.1)).collect::<Vec<_>>();
-            let formatters = gtk::StringList::from_iter(formatters);
-            self.dropdown_format.set_model(Some(&formatters));
+            set_labels(&self.dropdown_separator, SEPARATORS);
+            set_labels(&self.dropdown_format, FORMATS);
+            set_labels(&self.dropdown_csv_delimiter, CSV_DELIMITERS);
+            set_labels(&self.dropdown_quoting, QUOTINGS);
+            set_labels(&self.dropdown_latex_rules, LATEX_RULES);
+            set_labels(&self.dropdown_sql_dialect, SQL_DIALECTS);
+            set_labels(&self.dropdown_box_style, BOX_STYLES);
 
             self.obj().init();
         }
@@ -116,41 +156,125 @@ impl TabelaWindow {
         let imp = self.imp();
         let this = self;
 
+        let open_action = gio::ActionEntry::builder("open")
+            .activate(move |win: &Self, _, _| win.open())
+            .build();
+        let save_action = gio::ActionEntry::builder("save")
+            .activate(move |win: &Self, _, _| win.save())
+            .build();
+        let save_as_action = gio::ActionEntry::builder("save-as")
+            .activate(move |win: &Self, _, _| win.save_as())
+            .build();
+        let copy_output_action = gio::ActionEntry::builder("copy-output")
+            .activate(move |win: &Self, _, _| win.copy_output())
+            .build();
+        let paste_html_action = gio::ActionEntry::builder("paste-html")
+            .activate(move |win: &Self, _, _| win.paste_html())
+            .build();
+        self.add_action_entries([
+            open_action,
+            save_action,
+            save_as_action,
+            copy_output_action,
+            paste_html_action,
+        ]);
+
+        // Files dragged from the file manager are opened; the capture phase comes
+        // before the text view's own drop handling, which still inserts dropped text.
+        let drop_target = gtk::DropTarget::new(gdk::FileList::static_type(), gdk::DragAction::COPY);
+        drop_target.set_propagation_phase(gtk::PropagationPhase::Capture);
+        drop_target.connect_drop(clone!(
+            #[weak]
+            this,
+            #[upgrade_or]
+            false,
+            move |_, value, _, _| this.drop_files(value)
+        ));
+        imp.text_input.add_controller(drop_target);
+
         imp.text_input.buffer().connect_changed(clone! {
             #[strong] this,
             move |_| this.compute()
         });
-        imp.dropdown_separator.connect_selected_item_notify(clone! {
-            #[strong] this,
-            move |_| this.compute()
-        });
-        imp.dropdown_format.connect_selected_item_notify(clone! {
-            #[strong] this,
-            move |_| this.compute()
-        });
-        imp.switch_titles.connect_active_notify(clone! {
-            #[strong] this,
-            move |_| this.compute()
-        });
-        imp.switch_remove_quotes.connect_active_notify(clone! {
-            #[strong] this,
-            move |_| this.compute()
-        });
+        for entry in [&imp.entry_separator, &imp.entry_table_name] {
+            entry.connect_changed(clone! {
+                #[strong] this,
+                move |_| this.compute()
+            });
+        }
+
+        let dropdowns = [
+            &imp.dropdown_separator,
+            &imp.dropdown_format,
+            &imp.dropdown_csv_delimiter,
+            &imp.dropdown_quoting,
+            &imp.dropdown_latex_rules,
+            &imp.dropdown_sql_dialect,
+            &imp.dropdown_box_style,
+        ];
+        for dropdown in dropdowns {
+            dropdown.connect_selected_item_notify(clone! {
+                #[strong] this,
+                move |_| this.compute()
+            });
+        }
+
+        let switches = [
+            &imp.switch_titles,
+            &imp.switch_remove_quotes,
+            &imp.switch_keep_markup,
+            &imp.switch_json_compact,
+            &imp.switch_json_strings,
+            &imp.switch_create_table,
+        ];
+        for switch in switches {
+            switch.connect_active_notify(clone! {
+                #[strong] this,
+                move |_| this.compute()
+            });
+        }
     }
 
     fn compute(&self) {
-        use formatting::Table;
-
         let imp = self.imp();
 
-        let formatter = Self::parse_format_option(imp.dropdown_format.selected() as usize);
-        let separator = Self::parse_separator_option(imp.dropdown_separator.selected() as usize);
         let input_buffer = imp.text_input.buffer();
         let text = input_buffer.text(&input_buffer.start_iter(), &input_buffer.end_iter(), true);
 
-        let table = Table::with_text_and_separator(
+        let separator_option =
+            Self::parse_separator_option(imp.dropdown_separator.selected() as usize);
+
+        imp.label_detected_separator
+            .set_visible(separator_option == Separator::Auto);
+        imp.entry_separator
+            .set_visible(separator_option == Separator::Custom);
+
+        let format = Self::parse_format_option(imp.dropdown_format.selected() as usize);
+        let mut format_options = self.format_options();
+        format_options.adapt_to(separator_option);
+        let formatter = format.formatter(&format_options);
+
+        // Markdown read from Markdown is always passed through
+        imp.box_markdown_options
+            .set_visible(format == Format::Markdown && separator_option != Separator::Markdown);
+        imp.box_csv_options.set_visible(format == Format::Csv);
+        imp.box_latex_options.set_visible(format == Format::Latex);
+        imp.box_json_options.set_visible(format == Format::Json);
+        imp.box_sql_options.set_visible(format == Format::Sql);
+        imp.box_text_options.set_visible(format == Format::Text);
+
+        let separator_option = if separator_option == Separator::Auto {
+            let separator = presets::detect_separator(text.as_str());
+            imp.label_detected_separator
+                .set_label(&Self::separator_name(separator));
+            Separator::Fixed(separator)
+        } else {
+            separator_option
+        };
+
+        let table = separator_option.read(
             text.as_str(),
-            separator,
+            &imp.entry_separator.text(),
             imp.switch_titles.state(),
             imp.switch_remove_quotes.state(),
         );
@@ -159,24 +283,367 @@ impl TabelaWindow {
         imp.text_output.buffer().set_text(&result);
     }
 
-    fn parse_separator_option(separator_option: usize) -> char {
-        if separator_option <= SEPARATORS.len() {
-            SEPARATORS[separator_option].0
+    fn drop_files(&self, value: &glib::Value) -> bool {
+        let file = value
+            .get::<gdk::FileList>()
+            .ok()
+            .and_then(|files| files.files().into_iter().next());
+
+        match file {
+            Some(file) => {
+                self.open_file(&file);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Asks for a file to read into the input.
+    fn open(&self) {
+        let tables = gtk::FileFilter::new();
+        tables.set_name(Some(&gettext("Tables")));
+        for (extension, _) in EXTENSIONS {
+            tables.add_suffix(extension);
+        }
+
+        let all_files = gtk::FileFilter::new();
+        all_files.set_name(Some(&gettext("All Files")));
+        all_files.add_pattern("*");
+
+        let filters = gio::ListStore::new::<gtk::FileFilter>();
+        filters.append(&tables);
+        filters.append(&all_files);
+
+        let dialog = gtk::FileDialog::builder()
+            .title(gettext("Open Table"))
+            .modal(true)
+            .filters(&filters)
+            .default_filter(&tables)
+            .build();
+
+        glib::spawn_future_local(clone!(
+            #[weak(rename_to = this)]
+            self,
+            async move {
+                match dialog.open_future(Some(&this)).await {
+                    Ok(file) => this.open_file(&file),
+                    Err(err) => glib::g_info!("tabela", "No file opened: {err}"),
+                }
+            }
+        ));
+    }
+
+    /// Reads a file into the input, choosing how to read it by its extension.
+    pub fn open_file(&self, file: &gio::File) {
+        glib::spawn_future_local(clone!(
+            #[weak(rename_to = this)]
+            self,
+            #[strong]
+            file,
+            async move {
+                match file.load_contents_future().await {
+                    Ok((bytes, _)) => match std::str::from_utf8(&bytes) {
+                        Ok(text) => this.set_input(&file, text),
+                        Err(_) => this.show_not_utf8(&file),
+                    },
+                    Err(err) => this.show_error(&gettext("Unable to open the file"), err.message()),
+                }
+            }
+        ));
+    }
+
+    /// Tells that a file can't be opened because it isn't UTF-8 text, rather
+    /// than loading it with replacement characters.
+    fn show_not_utf8(&self, file: &gio::File) {
+        let name = file
+            .basename()
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_else(|| file.uri().into());
+        let message = gettext("{}: the text is not valid UTF-8").replace("{}", &name);
+
+        self.imp()
+            .toast_overlay
+            .add_toast(adw::Toast::new(&message));
+    }
+
+    fn set_input(&self, file: &gio::File, text: &str) {
+        let imp = self.imp();
+
+        let separator = Separator::for_file(file)
+            .and_then(|separator| SEPARATORS.iter().position(|s| s.1 == separator));
+        if let Some(index) = separator {
+            imp.dropdown_separator.set_selected(index as u32);
+        }
+
+        let name = file
+            .basename()
+            .and_then(|p| p.file_stem().map(|s| s.to_string_lossy().into_owned()));
+        imp.input_name.replace(name);
+        imp.output_file.replace(None);
+
+        imp.text_input.buffer().set_text(text);
+    }
+
+    /// Saves the output where it was last saved, or asks where to save it if
+    /// it wasn't, or if it was in another format.
+    fn save(&self) {
+        let output = self.output_format();
+        let file = self
+            .imp()
+            .output_file
+            .borrow()
+            .as_ref()
+            .filter(|(_, format, extension)| (*format, *extension) == output)
+            .map(|(file, _, _)| file.clone());
+
+        match file {
+            Some(file) => self.save_to(file),
+            None => self.save_as(),
+        }
+    }
+
+    /// Asks where to save the output, suggesting the extension of its format.
+    fn save_as(&self) {
+        let imp = self.imp();
+
+        let (_, extension) = self.output_format();
+        let name = imp
+            .input_name
+            .borrow()
+            .clone()
+            .unwrap_or_else(|| gettext("table"));
+
+        let dialog = gtk::FileDialog::builder()
+            .title(gettext("Save Output"))
+            .modal(true)
+            .initial_name(format!("{name}.{extension}"))
+            .build();
+
+        glib::spawn_future_local(clone!(
+            #[weak(rename_to = this)]
+            self,
+            async move {
+                match dialog.save_future(Some(&this)).await {
+                    Ok(file) => this.save_to(file),
+                    Err(err) => glib::g_info!("tabela", "Output not saved: {err}"),
+                }
+            }
+        ));
+    }
+
+    fn save_to(&self, file: gio::File) {
+        let buffer = self.imp().text_output.buffer();
+        let text = buffer.text(&buffer.start_iter(), &buffer.end_iter(), true);
+        let (format, extension) = self.output_format();
+
+        glib::spawn_future_local(clone!(
+            #[weak(rename_to = this)]
+            self,
+            async move {
+                let result = file
+                    .replace_contents_future(
+                        text.as_bytes().to_vec(),
+                        None,
+                        false,
+                        gio::FileCreateFlags::REPLACE_DESTINATION,
+                    )
+                    .await;
+
+                match result {
+                    Ok(_) => {
+                        this.imp()
+                            .output_file
+                            .replace(Some((file, format, extension)));
+                    }
+                    Err((_, err)) => {
+                        this.show_error(&gettext("Unable to save the file"), err.message())
+                    }
+                }
+            }
+        ));
+    }
+
+    /// The format of the output and the extension of its files.
+    fn output_format(&self) -> (Format, &'static str) {
+        let format = Self::parse_format_option(self.imp().dropdown_format.selected() as usize);
+        (format, format.extension(&self.format_options()))
+    }
+
+    fn show_error(&self, heading: &str, body: &str) {
+        let dialog = adw::AlertDialog::new(Some(heading), Some(body));
+        dialog.add_response("close", &gettext("_Close"));
+        dialog.present(Some(self));
+    }
+
+    /// Copies the output to the clipboard. HTML is also offered as `text/html`,
+    /// so that word processors and mail clients paste a table instead of tags.
+    fn copy_output(&self) {
+        let imp = self.imp();
+
+        let buffer = imp.text_output.buffer();
+        let text = buffer.text(&buffer.start_iter(), &buffer.end_iter(), true);
+        let format = Self::parse_format_option(imp.dropdown_format.selected() as usize);
+
+        let clipboard = self.clipboard();
+        if format == Format::Html {
+            let provider = gdk::ContentProvider::new_union(&[
+                gdk::ContentProvider::for_bytes("text/html", &glib::Bytes::from(text.as_bytes())),
+                gdk::ContentProvider::for_value(&text.to_value()),
+            ]);
+            if let Err(err) = clipboard.set_content(Some(&provider)) {
+                glib::g_warning!("tabela", "Unable to copy the output: {err}");
+                return;
+            }
         } else {
-            glib::g_warning!(
-                "tabela",
-                "Invalid separator {separator_option}, assuming default"
-            );
-            SEPARATORS[0].0
+            clipboard.set_text(&text);
         }
+
+        imp.toast_overlay
+            .add_toast(adw::Toast::new(&gettext("Copied to clipboard")));
     }
 
-    fn parse_format_option(format_option: usize) -> &'static dyn formatting::Formatter {
-        if format_option <= FORMATTERS.len() {
-            FORMATTERS[format_option].0
+    /// Pastes the HTML flavour of the clipboard, if any, so that tables copied
+    /// from web pages or online spreadsheets can be read as tables; otherwise
+    /// falls back to pasting plain text.
+    fn paste_html(&self) {
+        let clipboard = self.clipboard();
+
+        glib::spawn_future_local(clone!(
+            #[weak(rename_to = this)]
+            self,
+            async move {
+                match Self::read_clipboard_html(&clipboard).await {
+                    Ok(html) => {
+                        if let Some(index) = SEPARATORS.iter().position(|s| s.1 == Separator::Html)
+                        {
+                            this.imp().dropdown_separator.set_selected(index as u32);
+                        }
+                        this.imp().text_input.buffer().set_text(&html);
+                    }
+                    Err(err) => {
+                        glib::g_info!("tabela", "No HTML in the clipboard ({err}), pasting text");
+                        if let Ok(Some(text)) = clipboard.read_text_future().await {
+                            this.imp().text_input.buffer().set_text(&text);
+                        }
+                    }
+                }
+            }
+        ));
+    }
+
+    async fn read_clipboard_html(clipboard: &gdk::Clipboard) -> Result<String, glib::Error> {
+        let (stream, _) = clipboard
+            .read_future(&["text/html"], glib::Priority::DEFAULT)
+            .await?;
+
+        let output = gio::MemoryOutputStream::new_resizable();
+        output
+            .splice_future(
+                &stream,
+                gio::OutputStreamSpliceFlags::CLOSE_SOURCE
+                    | gio::OutputStreamSpliceFlags::CLOSE_TARGET,
+                glib::Priority::DEFAULT,
+            )
+            .await?;
+
+        let bytes = output.steal_as_bytes();
+
+        // Some browsers put UTF-16 HTML on the clipboard
+        let html = match bytes.as_ref() {
+            [0xff, 0xfe, rest @ ..] => Self::decode_utf16(rest, u16::from_le_bytes),
+            [0xfe, 0xff, rest @ ..] => Self::decode_utf16(rest, u16::from_be_bytes),
+            [_, 0, ..] => Self::decode_utf16(&bytes, u16::from_le_bytes),
+            bytes => String::from_utf8_lossy(bytes).into_owned(),
+        };
+
+        Ok(html)
+    }
+
+    fn decode_utf16(bytes: &[u8], from_bytes: fn([u8; 2]) -> u16) -> String {
+        let units = bytes
+            .chunks_exact(2)
+            .map(|c| from_bytes([c[0], c[1]]))
+            .collect::<Vec<_>>();
+
+        String::from_utf16_lossy(&units)
+    }
+
+    fn separator_name(separator: &str) -> String {
+        SEPARATORS
+            .iter()
+            .find(|s| matches!(s.1, Separator::Fixed(f) if f == separator))
+            .map(|s| gettext(s.2))
+            .unwrap_or_else(|| separator.to_string())
+    }
+
+    fn parse_separator_option(separator_option: usize) -> Separator {
+        match SEPARATORS.get(separator_option) {
+            Some(separator) => separator.1,
+            None => {
+                glib::g_warning!(
+                    "tabela",
+                    "Invalid separator {separator_option}, assuming default"
+                );
+                SEPARATORS[0].1
+            }
+        }
+    }
+
+    fn parse_format_option(format_option: usize) -> Format {
+        match FORMATS.get(format_option) {
+            Some(format) => format.1,
+            None => {
+                glib::g_warning!("tabela", "Invalid format {format_option}, assuming default");
+                FORMATS[0].1
+            }
+        }
+    }
+
+    /// The options of the formats, as set by their controls.
+    fn format_options(&self) -> FormatOptions {
+        fn choice<T: Copy>(dropdown: &gtk::DropDown, choices: &[(&str, T, &str)]) -> T {
+            choices
+                .get(dropdown.selected() as usize)
+                .unwrap_or(&choices[0])
+                .1
+        }
+
+        let imp = self.imp();
+
+        let table_name = imp.entry_table_name.text();
+        let table_name = if table_name.trim().is_empty() {
+            presets::DEFAULT_TABLE_NAME.to_string()
         } else {
-            glib::g_warning!("tabela", "Invalid format {format_option}, assuming default");
-            FORMATTERS[0].0
+            table_name.to_string()
+        };
+
+        FormatOptions {
+            markdown_escaping: if imp.switch_keep_markup.is_active() {
+                MarkdownEscaping::PipesOnly
+            } else {
+                MarkdownEscaping::Markup
+            },
+            csv_delimiter: choice(&imp.dropdown_csv_delimiter, CSV_DELIMITERS),
+            quoting: choice(&imp.dropdown_quoting, QUOTINGS),
+            latex_rules: choice(&imp.dropdown_latex_rules, LATEX_RULES),
+            json_layout: if imp.switch_json_compact.is_active() {
+                JsonLayout::Compact
+            } else {
+                JsonLayout::Pretty
+            },
+            json_values: if imp.switch_json_strings.is_active() {
+                JsonValues::Strings
+            } else {
+                JsonValues::Typed
+            },
+            sql_dialect: choice(&imp.dropdown_sql_dialect, SQL_DIALECTS),
+            sql_statements: if imp.switch_create_table.is_active() {
+                SqlStatements::CreateAndInsert
+            } else {
+                SqlStatements::InsertOnly
+            },
+            table_name,
+            box_style: choice(&imp.dropdown_box_style, BOX_STYLES),
         }
     }
 }
@@ -23,16 +23,17 @@
  * SPDX-License-Identifier: MIT
  */
 
-mod html_formatter;
-mod markdown_formatter;
-mod split_line;
-mod table;
-
-pub use html_formatter::HtmlFormatter;
-pub use markdown_formatter::MarkdownFormatter;
-pub use split_line::split_line;
-pub use table::Table;
-
-pub trait Formatter {
-    fn format(&self, table: Table) -> String;
+/// How the cells of a column are aligned.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Alignment {
+    /// Right for columns made only of numbers, the default of the output
+    /// format (usually left) for the others
+    #[default]
+    Auto,
+    /// Cells start at the left edge
+    Left,
+    /// Cells are centered
+    Center,
+    /// Cells end at the right edge
+    Right,
 }
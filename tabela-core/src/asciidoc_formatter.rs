@@ -0,0 +1,127 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Formatter, Table};
+use std::borrow::Cow;
+
+/// Formats tables as AsciiDoc tables, with a `cols` attribute so that
+/// cells with line breaks are still read right.
+pub struct AsciiDocFormatter;
+
+impl AsciiDocFormatter {
+    fn escape(s: &str) -> Cow<'_, str> {
+        if s.contains(['|', '\n']) {
+            s.replace('|', "\\|")
+                .lines()
+                .collect::<Vec<_>>()
+                .join(" +\n")
+                .into()
+        } else {
+            s.into()
+        }
+    }
+
+    fn format_row(result: &mut String, columns: usize, row: &[Cow<'_, str>]) {
+        for index in 0..columns {
+            if index != 0 {
+                result.push(' ');
+            }
+
+            result.push('|');
+
+            if let Some(cell) = row.get(index) {
+                result.push_str(&Self::escape(cell.trim()));
+            }
+        }
+        result.push('\n');
+    }
+}
+
+impl Formatter for AsciiDocFormatter {
+    fn format(&self, table: Table) -> String {
+        let columns = table.columns();
+
+        let mut result = String::new();
+
+        // An empty `cols` is invalid, so it's left out of empty tables
+        let mut attributes = Vec::new();
+        if columns != 0 {
+            attributes.push(format!("cols=\"{}\"", vec!["1"; columns].join(",")));
+        }
+        if table.titles().is_some() {
+            attributes.push("options=\"header\"".to_string());
+        }
+        if !attributes.is_empty() {
+            result.push_str(&format!("[{}]\n", attributes.join(",")));
+        }
+
+        result.push_str("|===\n");
+
+        if let Some(head) = table.titles() {
+            Self::format_row(&mut result, columns, head);
+            result.push('\n');
+        }
+
+        for row in table.rows() {
+            Self::format_row(&mut result, columns, row);
+        }
+
+        result.push_str("|===\n");
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsciiDocFormatter, Formatter, Table};
+
+    #[test]
+    fn asciidoc_titles() {
+        let t = Table::with_text_and_separator("a,b\n1,2\n3\n", ",", true, true);
+
+        assert_eq!(
+            AsciiDocFormatter.format(t),
+            "[cols=\"1,1\",options=\"header\"]\n|===\n|a |b\n\n|1 |2\n|3 |\n|===\n"
+        );
+    }
+
+    #[test]
+    fn asciidoc_no_titles_escapes() {
+        let t = Table::with_text_and_separator("a|b,\"c\nd\"\n", ",", false, true);
+
+        assert_eq!(
+            AsciiDocFormatter.format(t),
+            "[cols=\"1,1\"]\n|===\n|a\\|b |c +\nd\n|===\n"
+        );
+    }
+
+    #[test]
+    fn asciidoc_empty() {
+        let t = Table::with_text_and_separator("", ",", false, true);
+
+        assert_eq!(AsciiDocFormatter.format(t), "|===\n|===\n");
+    }
+}
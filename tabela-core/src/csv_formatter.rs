@@ -0,0 +1,159 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Formatter, Table, is_number};
+use std::borrow::Cow;
+
+/// Which cells get enclosed in double quotes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Quoting {
+    /// Only cells containing separators, quotes or line breaks
+    Minimal,
+    /// Every cell
+    All,
+    /// Every cell which is not a number
+    NonNumeric,
+}
+
+/// Formats tables as RFC 4180 CSV, or any other separated values format
+/// following the same rules (like TSV).
+pub struct CsvFormatter {
+    separator: Cow<'static, str>,
+    quoting: Quoting,
+}
+
+impl CsvFormatter {
+    /// Creates a formatter separating cells with `separator` (like `,`, `;`
+    /// or a TAB) and quoting them according to `quoting`.
+    pub fn new(separator: impl Into<Cow<'static, str>>, quoting: Quoting) -> Self {
+        Self {
+            separator: separator.into(),
+            quoting,
+        }
+    }
+
+    fn needs_quotes(&self, cell: &str) -> bool {
+        match self.quoting {
+            Quoting::All => true,
+            Quoting::NonNumeric => !is_number(cell),
+            Quoting::Minimal => cell.contains(&*self.separator) || cell.contains(['"', '\n', '\r']),
+        }
+    }
+
+    fn quote<'c>(&self, cell: &'c str) -> Cow<'c, str> {
+        if self.needs_quotes(cell) {
+            format!("\"{}\"", cell.replace('"', "\"\"")).into()
+        } else {
+            cell.into()
+        }
+    }
+
+    fn format_row(&self, result: &mut String, columns: usize, row: &[Cow<'_, str>]) {
+        for index in 0..columns {
+            if index != 0 {
+                result.push_str(&self.separator);
+            }
+
+            let cell = row.get(index).map(|c| c.trim()).unwrap_or_default();
+            result.push_str(&self.quote(cell));
+        }
+        result.push_str("\r\n");
+    }
+}
+
+impl Formatter for CsvFormatter {
+    fn format(&self, table: Table) -> String {
+        let columns = table.columns();
+
+        let mut result = String::new();
+
+        if let Some(head) = table.titles() {
+            self.format_row(&mut result, columns, head);
+        }
+
+        for row in table.rows() {
+            self.format_row(&mut result, columns, row);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CsvFormatter, Quoting};
+    use crate::{Formatter, Table};
+
+    fn test(formatter: CsvFormatter, text: &str, expected: &str) {
+        let t = Table::with_text_and_separator(text, "\t", true, true);
+
+        assert_eq!(formatter.format(t), expected);
+    }
+
+    #[test]
+    fn csv_minimal() {
+        test(
+            CsvFormatter::new(",", Quoting::Minimal),
+            "a\tb\n1,5\tsay \"hi\"\n2\tplain\n",
+            "a,b\r\n\"1,5\",\"say \"\"hi\"\"\"\r\n2,plain\r\n",
+        );
+    }
+
+    #[test]
+    fn csv_all() {
+        test(
+            CsvFormatter::new(",", Quoting::All),
+            "a\tb\n1\t\n",
+            "\"a\",\"b\"\r\n\"1\",\"\"\r\n",
+        );
+    }
+
+    #[test]
+    fn csv_non_numeric() {
+        test(
+            CsvFormatter::new(";", Quoting::NonNumeric),
+            "name\tprice\napple\t1.5\n",
+            "\"name\";\"price\"\r\n\"apple\";1.5\r\n",
+        );
+    }
+
+    #[test]
+    fn csv_line_breaks() {
+        test(
+            CsvFormatter::new(",", Quoting::Minimal),
+            "a\tb\n\"one\ntwo\"\tx\n",
+            "a,b\r\n\"one\ntwo\",x\r\n",
+        );
+    }
+
+    #[test]
+    fn csv_pads_rows() {
+        test(
+            CsvFormatter::new("\t", Quoting::Minimal),
+            "a\tb\tc\n1\n",
+            "a\tb\tc\r\n1\t\t\r\n",
+        );
+    }
+}
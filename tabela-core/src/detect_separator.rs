@@ -0,0 +1,143 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{split_line, split_records};
+
+/// Separators considered by `detect_separator`, in order of preference
+/// when more than one of them fits the text equally well.
+const CANDIDATES: &[&str] = &["\t", ",", ";", "|"];
+
+/// Number of records looked at; pasted tables can be huge and the first
+/// records are more than enough to make up our mind.
+const SAMPLE_RECORDS: usize = 100;
+
+/// Guesses the separator of a text, by picking the candidate which splits
+/// the most records into the same number of columns (and, among those, the
+/// one producing more columns). Quotes are honoured, so separators inside
+/// quoted cells do not count.
+///
+/// Returns `None` if no candidate splits the text in at least two columns.
+pub fn detect_separator(text: &str) -> Option<&'static str> {
+    let mut best = None;
+    let mut best_score = (0, 0);
+
+    for separator in CANDIDATES.iter().copied() {
+        let counts = split_records(text, separator)
+            .filter(|r| !r.is_empty())
+            .take(SAMPLE_RECORDS)
+            .map(|r| split_line(r, separator, true).count())
+            .collect::<Vec<_>>();
+
+        let Some(score) = score(&counts) else {
+            continue;
+        };
+
+        if score > best_score {
+            best_score = score;
+            best = Some(separator);
+        }
+    }
+
+    best
+}
+
+/// Returns how many records share the most common column count, and that
+/// column count; `None` if the records are not split at all.
+fn score(counts: &[usize]) -> Option<(usize, usize)> {
+    let mut best = None;
+
+    for &columns in counts {
+        if columns < 2 {
+            continue;
+        }
+
+        let records = counts.iter().filter(|&&c| c == columns).count();
+
+        if best.is_none_or(|b| (records, columns) > b) {
+            best = Some((records, columns));
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::detect_separator;
+
+    #[test]
+    fn detect_tab() {
+        assert_eq!(detect_separator("a\tb\tc\n1\t2\t3\n"), Some("\t"));
+    }
+
+    #[test]
+    fn detect_comma() {
+        assert_eq!(detect_separator("a,b,c\n1,2,3\n"), Some(","));
+    }
+
+    #[test]
+    fn detect_semicolon_with_decimal_commas() {
+        assert_eq!(
+            detect_separator("Name;Price\nApple;1,50\nPear;2,00\nPlum;3\n"),
+            Some(";")
+        );
+    }
+
+    #[test]
+    fn detect_ignores_quoted_separators() {
+        assert_eq!(
+            detect_separator("\"a;b\",\"c;d\"\n\"e;f\",\"g;h\"\n\"i\",\"j\"\n"),
+            Some(",")
+        );
+    }
+
+    #[test]
+    fn detect_tab_with_commas_in_cells() {
+        assert_eq!(
+            detect_separator("Name\tNotes\nAlice\tone, two\nBob\tthree\n"),
+            Some("\t")
+        );
+    }
+
+    #[test]
+    fn detect_prefers_consistency() {
+        assert_eq!(detect_separator("a;b;c,d\ne;f;g\nh;i;j,k,l,m\n"), Some(";"));
+    }
+
+    #[test]
+    fn detect_pipe() {
+        assert_eq!(detect_separator("a|b|c\n1|2|3\n"), Some("|"));
+    }
+
+    #[test]
+    fn detect_single_column() {
+        assert_eq!(detect_separator("a\nb\nc\n"), None);
+    }
+
+    #[test]
+    fn detect_empty() {
+        assert_eq!(detect_separator(""), None);
+    }
+}
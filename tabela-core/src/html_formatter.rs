@@ -23,9 +23,11 @@
  * SPDX-License-Identifier: MIT
  */
 
-use super::{Formatter, Table};
+use super::{Alignment, Formatter, Table};
 use std::borrow::Cow;
 
+/// Formats tables as a plain HTML `<table>`, without any styling other than
+/// the alignment of the columns.
 pub struct HtmlFormatter;
 
 impl HtmlFormatter {
@@ -54,11 +56,29 @@ impl HtmlFormatter {
         }
     }
 
-    fn format_row(result: &mut String, row: &[Cow<'_, str>], tag: &str) {
+    fn line_breaks(s: Cow<'_, str>) -> Cow<'_, str> {
+        if s.contains('\n') {
+            s.lines().collect::<Vec<_>>().join("<br>").into()
+        } else {
+            s
+        }
+    }
+
+    fn style(alignment: Alignment) -> &'static str {
+        match alignment {
+            Alignment::Auto => "",
+            Alignment::Left => " style=\"text-align:left\"",
+            Alignment::Center => " style=\"text-align:center\"",
+            Alignment::Right => " style=\"text-align:right\"",
+        }
+    }
+
+    fn format_row(result: &mut String, alignments: &[Alignment], row: &[Cow<'_, str>], tag: &str) {
         result.push_str("\t<tr>\n");
-        for cell in row.iter().map(|c| c.trim()) {
-            let text = Self::escape(cell);
-            result.push_str(&format!("\t\t<{tag}>{text}</{tag}>\n"));
+        for (index, cell) in row.iter().map(|c| c.trim()).enumerate() {
+            let text = Self::line_breaks(Self::escape(cell));
+            let style = Self::style(alignments.get(index).copied().unwrap_or_default());
+            result.push_str(&format!("\t\t<{tag}{style}>{text}</{tag}>\n"));
         }
         result.push_str("\t</tr>\n");
     }
@@ -66,16 +86,17 @@ impl HtmlFormatter {
 
 impl Formatter for HtmlFormatter {
     fn format(&self, table: Table) -> String {
+        let alignments = table.alignments();
         let mut result = String::new();
 
         result.push_str("<table>\n");
 
         if let Some(head) = table.titles() {
-            Self::format_row(&mut result, head, "th");
+            Self::format_row(&mut result, &alignments, head, "th");
         }
 
         for row in table.rows() {
-            Self::format_row(&mut result, row, "td");
+            Self::format_row(&mut result, &alignments, row, "td");
         }
 
         result.push_str("</table>\n");
@@ -88,7 +109,7 @@ impl Formatter for HtmlFormatter {
 mod tests {
     use std::borrow::Cow;
 
-    use crate::formatting::HtmlFormatter;
+    use crate::{Formatter, HtmlFormatter, Table};
 
     #[test]
     fn html_escapes() {
@@ -102,4 +123,25 @@ mod tests {
             panic!();
         }
     }
+
+    #[test]
+    fn html_line_breaks() {
+        let t = Table::with_text_and_separator("a,\"b\r\nc\"", ",", false, true);
+
+        assert_eq!(
+            HtmlFormatter.format(t),
+            "<table>\n\t<tr>\n\t\t<td>a</td>\n\t\t<td>b<br>c</td>\n\t</tr>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn html_alignments() {
+        let t = Table::with_text_and_separator("n,s\n1,a\n", ",", true, true);
+
+        assert_eq!(
+            HtmlFormatter.format(t),
+            "<table>\n\t<tr>\n\t\t<th style=\"text-align:right\">n</th>\n\t\t<th>s</th>\n\t</tr>\n\
+             \t<tr>\n\t\t<td style=\"text-align:right\">1</td>\n\t\t<td>a</td>\n\t</tr>\n</table>\n"
+        );
+    }
 }
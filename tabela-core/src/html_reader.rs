@@ -0,0 +1,561 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use std::borrow::Cow;
+
+/// Reads the first HTML table found in a text, returning its titles and rows.
+///
+/// This is not a full HTML parser, just enough to read the tables generated by
+/// spreadsheets and browsers: titles are the first row of a `<thead>`, or the
+/// first row if made only of `<th>` cells. Tags inside cells are dropped
+/// (except `<br>` and paragraphs, which become line breaks), entities are
+/// decoded, and cells spanning multiple columns or rows (`colspan` and
+/// `rowspan`) leave blank cells in the positions they cover.
+pub fn read_html(text: &str) -> (Vec<Cow<'_, str>>, Vec<Vec<Cow<'_, str>>>) {
+    let mut reader = HtmlReader::default();
+    let mut rest = text;
+
+    while let Some(lt) = rest.find('<') {
+        reader.push_text(&rest[..lt]);
+        rest = &rest[lt..];
+
+        let Some((tag, after)) = Tag::parse(rest) else {
+            reader.push_text("<");
+            rest = &rest[1..];
+            continue;
+        };
+
+        rest = after;
+
+        if !tag.closing && (tag.is("script") || tag.is("style")) {
+            rest = skip_raw_text(rest, tag.name);
+            continue;
+        }
+
+        if reader.handle_tag(&tag) {
+            break;
+        }
+    }
+
+    if !reader.done {
+        reader.push_text(rest);
+        reader.end_row();
+    }
+
+    let mut rows = reader.rows;
+    let head = if reader.thead_first || rows.first().is_some_and(|r| r.all_th) {
+        rows.remove(0).cells
+    } else {
+        Vec::new()
+    };
+
+    (head, rows.into_iter().map(|r| r.cells).collect())
+}
+
+struct Tag<'t> {
+    name: &'t str,
+    attributes: &'t str,
+    closing: bool,
+}
+
+impl<'t> Tag<'t> {
+    const UNNAMED: Self = Self {
+        name: "",
+        attributes: "",
+        closing: false,
+    };
+
+    /// Parses the tag at the start of `s`, returning it and the text after it.
+    /// Comments, doctypes and the like are returned as tags with no name, and
+    /// `None` is returned if there's no tag at all (like in `a < b`).
+    fn parse(s: &'t str) -> Option<(Self, &'t str)> {
+        if s.starts_with("<!--") {
+            return Some((Self::UNNAMED, skip_past(s, "-->")));
+        }
+
+        if s.starts_with("<!") || s.starts_with("<?") {
+            return Some((Self::UNNAMED, skip_past(s, ">")));
+        }
+
+        let body = &s[1..];
+        let (closing, body) = match body.strip_prefix('/') {
+            Some(body) => (true, body),
+            None => (false, body),
+        };
+
+        let name_len = body
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(body.len());
+
+        if name_len == 0 {
+            return None;
+        }
+
+        let mut quote = None;
+        let end = body.char_indices().skip(name_len).find(|&(_, c)| {
+            match quote {
+                Some(q) if q == c => quote = None,
+                Some(_) => {}
+                None if c == '"' || c == '\'' => quote = Some(c),
+                None => return c == '>',
+            }
+            false
+        })?;
+
+        let tag = Self {
+            name: &body[..name_len],
+            attributes: &body[name_len..end.0],
+            closing,
+        };
+
+        Some((tag, &body[end.0 + 1..]))
+    }
+
+    fn is(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+
+    fn attribute(&self, name: &str) -> Option<&'t str> {
+        let mut rest = self
+            .attributes
+            .trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+
+        while !rest.is_empty() {
+            let name_len = rest
+                .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+                .unwrap_or(rest.len());
+            let attr_name = &rest[..name_len];
+            rest = rest[name_len..].trim_start();
+
+            let mut value = "";
+            if let Some(after) = rest.strip_prefix('=') {
+                let after = after.trim_start();
+                let (v, r) = match after.chars().next() {
+                    Some(q @ ('"' | '\'')) => {
+                        let end = after[1..].find(q).map(|i| i + 1).unwrap_or(after.len());
+                        (&after[1..end], after.get(end + 1..).unwrap_or(""))
+                    }
+                    _ => {
+                        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                        (&after[..end], &after[end..])
+                    }
+                };
+                value = v;
+                rest = r;
+            }
+
+            if attr_name.eq_ignore_ascii_case(name) {
+                return Some(value);
+            }
+
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+        }
+
+        None
+    }
+
+    fn span(&self, name: &str) -> usize {
+        self.attribute(name)
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .unwrap_or(1)
+            .clamp(1, 1000)
+    }
+}
+
+fn skip_past<'t>(s: &'t str, end: &str) -> &'t str {
+    s.find(end).map(|i| &s[i + end.len()..]).unwrap_or("")
+}
+
+fn skip_raw_text<'t>(s: &'t str, name: &str) -> &'t str {
+    let closing = format!("</{}", name.to_ascii_lowercase());
+    let lower = s.to_ascii_lowercase();
+
+    match lower.find(&closing) {
+        Some(i) => s[i..].find('>').map(|j| &s[i + j + 1..]).unwrap_or(""),
+        None => "",
+    }
+}
+
+#[derive(Default)]
+struct Row<'a> {
+    cells: Vec<Cow<'a, str>>,
+    all_th: bool,
+}
+
+#[derive(Default)]
+struct Cell {
+    text: String,
+    colspan: usize,
+    rowspan: usize,
+}
+
+#[derive(Default)]
+struct HtmlReader<'a> {
+    rows: Vec<Row<'a>>,
+    /// Whether the first row comes from a `<thead>`
+    thead_first: bool,
+    row: Option<Row<'a>>,
+    cell: Option<Cell>,
+    /// Number of rows still covered by a `rowspan`, for each column
+    spans: Vec<usize>,
+    /// Columns covered by a `rowspan` from a previous row in the current row
+    covered: Vec<bool>,
+    depth: usize,
+    in_thead: bool,
+    done: bool,
+}
+
+impl<'a> HtmlReader<'a> {
+    /// Handles a tag, returning `true` once the first table is over.
+    fn handle_tag(&mut self, tag: &Tag<'_>) -> bool {
+        if tag.is("table") {
+            if tag.closing {
+                self.depth = self.depth.saturating_sub(1);
+                if self.depth == 0 {
+                    self.end_row();
+                    self.done = true;
+                    return true;
+                }
+            } else {
+                self.depth += 1;
+            }
+            return false;
+        }
+
+        // Tags of nested tables just separate their text
+        if self.depth > 1 {
+            self.push_text(" ");
+            return false;
+        }
+
+        if tag.is("thead") {
+            self.end_row();
+            self.in_thead = !tag.closing;
+        } else if tag.is("tr") {
+            self.end_row();
+            if !tag.closing {
+                self.start_row();
+            }
+        } else if tag.is("td") || tag.is("th") {
+            self.end_cell();
+            if !tag.closing {
+                if self.row.is_none() {
+                    self.start_row();
+                }
+                if let Some(row) = self.row.as_mut() {
+                    row.all_th &= tag.is("th");
+                }
+                self.cell = Some(Cell {
+                    text: String::new(),
+                    colspan: tag.span("colspan"),
+                    rowspan: tag.span("rowspan"),
+                });
+            }
+        } else if tag.is("br") || tag.is("p") || tag.is("div") || tag.is("li") {
+            if let Some(cell) = self.cell.as_mut() {
+                cell.text.push('\n');
+            }
+        } else if tag.is("tbody") || tag.is("tfoot") {
+            self.end_row();
+        }
+
+        false
+    }
+
+    fn push_text(&mut self, text: &str) {
+        let Some(cell) = self.cell.as_mut() else {
+            return;
+        };
+
+        for chr in text.chars() {
+            if chr.is_whitespace() {
+                if !cell.text.ends_with([' ', '\n']) {
+                    cell.text.push(' ');
+                }
+            } else {
+                cell.text.push(chr);
+            }
+        }
+    }
+
+    fn start_row(&mut self) {
+        self.covered = self.spans.iter().map(|&s| s > 0).collect();
+        for span in self.spans.iter_mut() {
+            *span = span.saturating_sub(1);
+        }
+
+        self.row = Some(Row {
+            cells: Vec::new(),
+            all_th: true,
+        });
+    }
+
+    fn end_cell(&mut self) {
+        let (Some(cell), Some(row)) = (self.cell.take(), self.row.as_mut()) else {
+            return;
+        };
+
+        while self.covered.get(row.cells.len()).copied().unwrap_or(false) {
+            row.cells.push(Cow::Borrowed(""));
+        }
+
+        let column = row.cells.len();
+        row.cells.push(Cow::Owned(decode_entities(&cell.text)));
+        for _ in 1..cell.colspan {
+            row.cells.push(Cow::Borrowed(""));
+        }
+
+        if cell.rowspan > 1 {
+            let end = column + cell.colspan;
+            if self.spans.len() < end {
+                self.spans.resize(end, 0);
+            }
+            for span in &mut self.spans[column..end] {
+                *span = (*span).max(cell.rowspan - 1);
+            }
+        }
+    }
+
+    fn end_row(&mut self) {
+        self.end_cell();
+
+        let Some(mut row) = self.row.take() else {
+            return;
+        };
+
+        if let Some(last) = self.covered.iter().rposition(|&c| c) {
+            while row.cells.len() <= last {
+                row.cells.push(Cow::Borrowed(""));
+            }
+        }
+
+        if row.cells.is_empty() {
+            return;
+        }
+
+        if self.in_thead && self.rows.is_empty() {
+            self.thead_first = true;
+        }
+
+        self.rows.push(row);
+    }
+}
+
+/// Decodes HTML entities, turns non-breaking spaces into plain spaces and
+/// trims every line of text.
+fn decode_entities(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(amp) = rest.find('&') {
+        result.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|&semi| semi <= 10)
+            .and_then(|semi| decode_entity(&rest[1..semi + 1]).map(|c| (c, semi + 2)));
+
+        match decoded {
+            Some((chr, len)) => {
+                result.push(chr);
+                rest = &rest[len..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+
+    result
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(num) = entity.strip_prefix('#') {
+        let code = match num.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => num.parse().ok()?,
+        };
+        return char::from_u32(code).map(|c| if c == '\u{a0}' { ' ' } else { c });
+    }
+
+    if let Some(index) = LATIN_1.iter().position(|&name| name == entity) {
+        // The list starts at U+00A0, the no-break space, which is read as a space
+        return char::from_u32(0xa0 + index as u32).map(|c| if c == '\u{a0}' { ' ' } else { c });
+    }
+
+    let chr = match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "euro" => '€',
+        _ => return None,
+    };
+
+    Some(chr)
+}
+
+/// The names of the entities for U+00A0 to U+00FF, the Latin-1 characters.
+const LATIN_1: [&str; 96] = [
+    "nbsp", "iexcl", "cent", "pound", "curren", "yen", "brvbar", "sect", "uml", "copy", "ordf",
+    "laquo", "not", "shy", "reg", "macr", "deg", "plusmn", "sup2", "sup3", "acute", "micro",
+    "para", "middot", "cedil", "sup1", "ordm", "raquo", "frac14", "frac12", "frac34", "iquest",
+    "Agrave", "Aacute", "Acirc", "Atilde", "Auml", "Aring", "AElig", "Ccedil", "Egrave", "Eacute",
+    "Ecirc", "Euml", "Igrave", "Iacute", "Icirc", "Iuml", "ETH", "Ntilde", "Ograve", "Oacute",
+    "Ocirc", "Otilde", "Ouml", "times", "Oslash", "Ugrave", "Uacute", "Ucirc", "Uuml", "Yacute",
+    "THORN", "szlig", "agrave", "aacute", "acirc", "atilde", "auml", "aring", "aelig", "ccedil",
+    "egrave", "eacute", "ecirc", "euml", "igrave", "iacute", "icirc", "iuml", "eth", "ntilde",
+    "ograve", "oacute", "ocirc", "otilde", "ouml", "divide", "oslash", "ugrave", "uacute", "ucirc",
+    "uuml", "yacute", "thorn", "yuml",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::read_html;
+
+    fn test(text: &str, titles: &[&str], expected: &[&[&str]]) {
+        let (head, rows) = read_html(text);
+
+        assert_eq!(head, titles);
+        assert_eq!(rows, expected);
+    }
+
+    #[test]
+    fn html_read_trivial() {
+        test(
+            "<table><tr><td>a</td><td>b</td></tr><tr><td>1</td><td>2</td></tr></table>",
+            &[],
+            &[&["a", "b"], &["1", "2"]],
+        );
+    }
+
+    #[test]
+    fn html_read_th_titles() {
+        test(
+            "<table>\n\t<tr>\n\t\t<th>a</th>\n\t\t<th>b</th>\n\t</tr>\n\t<tr>\n\t\t<td>1</td>\n\t\t<td>2</td>\n\t</tr>\n</table>\n",
+            &["a", "b"],
+            &[&["1", "2"]],
+        );
+    }
+
+    #[test]
+    fn html_read_thead() {
+        test(
+            "<TABLE><THEAD><TR><TD>a<TD>b</THEAD><TBODY><TR><TD>1<TD>2</TBODY></TABLE>",
+            &["a", "b"],
+            &[&["1", "2"]],
+        );
+    }
+
+    #[test]
+    fn html_read_entities() {
+        test(
+            "<table><tr><td>&lt;a&gt; &amp; &quot;b&quot;</td><td>caff&#232;&nbsp;&#x20AC;</td><td>&bogus; &</td></tr></table>",
+            &[],
+            &[&["<a> & \"b\"", "caffè €", "&bogus; &"]],
+        );
+    }
+
+    #[test]
+    fn html_read_inner_tags_and_whitespace() {
+        test(
+            "<table><tr><td>\n  <b>bold</b>   and\n <a href=\"x>y\">li<i>nk</i></a> </td><td>one<br>two<br/></td></tr></table>",
+            &[],
+            &[&["bold and link", "one\ntwo"]],
+        );
+    }
+
+    #[test]
+    fn html_read_colspan() {
+        test(
+            "<table><tr><td colspan=\"2\">a</td><td>b</td></tr><tr><td>1</td><td>2</td><td>3</td></tr></table>",
+            &[],
+            &[&["a", "", "b"], &["1", "2", "3"]],
+        );
+    }
+
+    #[test]
+    fn html_read_rowspan() {
+        test(
+            "<table><tr><td rowspan=2>a</td><td>b</td><td rowspan='3'>c</td></tr><tr><td>1</td></tr><tr><td>x</td><td>y</td></tr></table>",
+            &[],
+            &[&["a", "b", "c"], &["", "1", ""], &["x", "y", ""]],
+        );
+    }
+
+    #[test]
+    fn html_read_clipboard_fragment() {
+        test(
+            "<meta charset='utf-8'><style type=\"text/css\"><!--td {border: 1px solid #cccccc;}--></style><google-sheets-html-origin><table xmlns=\"http://www.w3.org/1999/xhtml\" cellspacing=\"0\" dir=\"ltr\"><colgroup><col width=\"100\"/></colgroup><tbody><tr style=\"height:21px;\"><td style=\"overflow:hidden;\">Artist</td><td>Album</td></tr><tr><td>Michael Jackson</td><td>Thriller</td></tr></tbody></table>",
+            &[],
+            &[&["Artist", "Album"], &["Michael Jackson", "Thriller"]],
+        );
+    }
+
+    #[test]
+    fn html_read_first_table_only() {
+        test(
+            "<!-- list --><table><tr><td>a<table><tr><td>nested</td></tr></table></td></tr></table><table><tr><td>b</td></tr></table>",
+            &[],
+            &[&["a nested"]],
+        );
+    }
+
+    #[test]
+    fn html_read_latin_1_entities() {
+        test(
+            "<table><tr><th>Nom</th></tr><tr><td>Ang&egrave;le M&uuml;ller</td></tr><tr><td>&Auml;rger &amp; Stra&szlig;e &agrave; &pound;5&nbsp;&frac12;</td></tr></table>",
+            &["Nom"],
+            &[&["Angèle Müller"], &["Ärger & Straße à £5 ½"]],
+        );
+    }
+
+    #[test]
+    fn html_read_less_than() {
+        test("<table><tr><td>a < b</td></tr></table>", &[], &[&["a < b"]]);
+    }
+
+    #[test]
+    fn html_read_no_table() {
+        test("just some <b>text</b>", &[], &[]);
+    }
+}
@@ -0,0 +1,111 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Formatter, Table};
+use std::borrow::Cow;
+
+/// Formats tables with the wiki markup of Jira and Confluence.
+pub struct JiraFormatter;
+
+impl JiraFormatter {
+    fn escape(cell: &str) -> Cow<'_, str> {
+        let cell = cell.trim();
+
+        // An empty cell would be swallowed by the surrounding separators
+        if cell.is_empty() {
+            return " ".into();
+        }
+
+        if !cell.contains(['|', '{', '[', '\n', '\r']) {
+            return cell.into();
+        }
+
+        let mut res = String::with_capacity(cell.len() + 4);
+
+        for (index, line) in cell.lines().enumerate() {
+            if index != 0 {
+                res.push_str(" \\\\ ");
+            }
+
+            for c in line.trim().chars() {
+                if matches!(c, '|' | '{' | '[') {
+                    res.push('\\');
+                }
+                res.push(c);
+            }
+        }
+
+        res.into()
+    }
+
+    fn format_row(result: &mut String, columns: usize, row: &[Cow<'_, str>], separator: &str) {
+        result.push_str(separator);
+        for index in 0..columns {
+            let cell = row.get(index).map(|c| c.as_ref()).unwrap_or_default();
+            result.push_str(&Self::escape(cell));
+            result.push_str(separator);
+        }
+        result.push('\n');
+    }
+}
+
+impl Formatter for JiraFormatter {
+    fn format(&self, table: Table) -> String {
+        let columns = table.columns();
+        let mut result = String::new();
+
+        if let Some(head) = table.titles() {
+            Self::format_row(&mut result, columns, head, "||");
+        }
+
+        for row in table.rows() {
+            Self::format_row(&mut result, columns, row, "|");
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Formatter, JiraFormatter, Table};
+
+    #[test]
+    fn jira_escapes() {
+        assert_eq!(
+            JiraFormatter::escape("a|b {code} [link]"),
+            "a\\|b \\{code} \\[link]"
+        );
+        assert_eq!(JiraFormatter::escape(" "), " ");
+        assert_eq!(JiraFormatter::escape("one\ntwo"), "one \\\\ two");
+    }
+
+    #[test]
+    fn jira_table() {
+        let t = Table::with_text_and_separator("a,b\n1,\n2,3\n", ",", true, true);
+
+        assert_eq!(JiraFormatter.format(t), "||a||b||\n|1| |\n|2|3|\n");
+    }
+}
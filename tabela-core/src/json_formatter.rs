@@ -0,0 +1,198 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Formatter, Table, Value, push_quoted};
+use std::borrow::Cow;
+
+/// How JSON is laid out.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JsonLayout {
+    /// Every row on its own line, and every field of an object too
+    Pretty,
+    /// Everything on one line, without spaces
+    Compact,
+}
+
+/// How cells are written as JSON values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JsonValues {
+    /// Cells looking like numbers, booleans or `null` are written as such
+    Typed,
+    /// Every cell is a string
+    Strings,
+}
+
+/// Formats tables as JSON: an array of objects keyed by the titles when the
+/// table has them, an array of arrays otherwise.
+pub struct JsonFormatter {
+    layout: JsonLayout,
+    values: JsonValues,
+}
+
+impl JsonFormatter {
+    /// Creates a formatter laying JSON out according to `layout` and writing
+    /// cells according to `values`.
+    pub const fn new(layout: JsonLayout, values: JsonValues) -> Self {
+        Self { layout, values }
+    }
+
+    fn push_value(&self, result: &mut String, cell: &str) {
+        let cell = cell.trim();
+
+        match Value::of(cell) {
+            _ if self.values == JsonValues::Strings => push_quoted(result, cell),
+            Value::Null => result.push_str("null"),
+            Value::Bool(b) => result.push_str(if b { "true" } else { "false" }),
+            Value::Number(n) => result.push_str(n),
+            Value::Text(t) => push_quoted(result, t),
+        }
+    }
+
+    fn push_array(&self, result: &mut String, columns: usize, row: &[Cow<'_, str>]) {
+        let separator = if self.layout == JsonLayout::Pretty {
+            ", "
+        } else {
+            ","
+        };
+
+        result.push('[');
+        for index in 0..columns {
+            if index != 0 {
+                result.push_str(separator);
+            }
+            self.push_value(
+                result,
+                row.get(index).map(|c| c.as_ref()).unwrap_or_default(),
+            );
+        }
+        result.push(']');
+    }
+
+    fn push_object(&self, result: &mut String, names: &[String], row: &[Cow<'_, str>]) {
+        let (open, separator, colon, close) = if self.layout == JsonLayout::Pretty {
+            ("{\n    ", ",\n    ", ": ", "\n  }")
+        } else {
+            ("{", ",", ":", "}")
+        };
+
+        result.push_str(open);
+        for (index, name) in names.iter().enumerate() {
+            if index != 0 {
+                result.push_str(separator);
+            }
+            push_quoted(result, name);
+            result.push_str(colon);
+            self.push_value(
+                result,
+                row.get(index).map(|c| c.as_ref()).unwrap_or_default(),
+            );
+        }
+        result.push_str(close);
+    }
+}
+
+impl Formatter for JsonFormatter {
+    fn format(&self, table: Table) -> String {
+        let names = table.titles().map(|_| table.column_names());
+        let columns = table.columns();
+        let mut result = String::new();
+
+        let (open, separator, close) = if self.layout == JsonLayout::Pretty {
+            ("[\n  ", ",\n  ", "\n]\n")
+        } else {
+            ("[", ",", "]\n")
+        };
+
+        if table.rows().next().is_none() {
+            result.push_str("[]\n");
+            return result;
+        }
+
+        result.push_str(open);
+        for (index, row) in table.rows().enumerate() {
+            if index != 0 {
+                result.push_str(separator);
+            }
+
+            match &names {
+                Some(names) => self.push_object(&mut result, names, row),
+                None => self.push_array(&mut result, columns, row),
+            }
+        }
+        result.push_str(close);
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Formatter, JsonFormatter, JsonLayout, JsonValues, Table};
+
+    const TEXT: &str = "id,name,,name\n1,\"a \"\"b\"\"\",true,007\n2,c\\d\n";
+
+    #[test]
+    fn json_objects() {
+        let t = Table::with_text_and_separator(TEXT, ",", true, true);
+
+        assert_eq!(
+            JsonFormatter::new(JsonLayout::Pretty, JsonValues::Typed).format(t),
+            "[\n  \
+             {\n    \"id\": 1,\n    \"name\": \"a \\\"b\\\"\",\n    \"column3\": true,\n    \"name_2\": \"007\"\n  },\n  \
+             {\n    \"id\": 2,\n    \"name\": \"c\\\\d\",\n    \"column3\": \"\",\n    \"name_2\": \"\"\n  }\n\
+             ]\n"
+        );
+    }
+
+    #[test]
+    fn json_arrays_compact() {
+        let t = Table::with_text_and_separator(TEXT, ",", false, true);
+
+        assert_eq!(
+            JsonFormatter::new(JsonLayout::Compact, JsonValues::Typed).format(t),
+            "[[\"id\",\"name\",\"\",\"name\"],[1,\"a \\\"b\\\"\",true,\"007\"],[2,\"c\\\\d\",\"\",\"\"]]\n"
+        );
+    }
+
+    #[test]
+    fn json_untyped() {
+        let t = Table::with_text_and_separator("1,null\n", ",", false, true);
+
+        assert_eq!(
+            JsonFormatter::new(JsonLayout::Pretty, JsonValues::Strings).format(t),
+            "[\n  [\"1\", \"null\"]\n]\n"
+        );
+    }
+
+    #[test]
+    fn json_empty() {
+        let t = Table::with_text_and_separator("a,b\n", ",", true, true);
+
+        assert_eq!(
+            JsonFormatter::new(JsonLayout::Pretty, JsonValues::Typed).format(t),
+            "[]\n"
+        );
+    }
+}
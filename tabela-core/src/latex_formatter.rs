@@ -0,0 +1,231 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Formatter, Table, column_widths, push_padded};
+use std::borrow::Cow;
+
+/// How the rows of a LaTeX table are ruled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LatexRules {
+    /// `\hline` between all the rows and vertical lines between columns
+    Hline,
+    /// `\toprule`, `\midrule` and `\bottomrule` from the booktabs package
+    Booktabs,
+}
+
+/// Formats tables as a LaTeX `tabular` environment.
+pub struct LatexFormatter {
+    rules: LatexRules,
+}
+
+impl LatexFormatter {
+    /// Creates a formatter ruling the table according to `rules`.
+    pub const fn new(rules: LatexRules) -> Self {
+        Self { rules }
+    }
+
+    fn escape(cell: &str) -> Cow<'_, str> {
+        let cell = cell.trim();
+
+        let needs_escaping = cell.contains([
+            '&', '%', '$', '#', '_', '{', '}', '~', '^', '\\', '\n', '\r',
+        ]);
+
+        // After `\\` or a booktabs rule, a `[` would open their optional argument
+        if let Some(rest) = cell.strip_prefix('[') {
+            return format!("{{[}}{}", Self::escape(rest)).into();
+        }
+
+        if !needs_escaping {
+            return cell.into();
+        }
+
+        let mut res = String::with_capacity(cell.len() + 8);
+
+        for (index, line) in cell.lines().enumerate() {
+            if index != 0 {
+                res.push(' ');
+            }
+
+            for c in line.trim().chars() {
+                match c {
+                    '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                        res.push('\\');
+                        res.push(c);
+                    }
+                    '~' => res.push_str("\\textasciitilde{}"),
+                    '^' => res.push_str("\\textasciicircum{}"),
+                    '\\' => res.push_str("\\textbackslash{}"),
+                    c => res.push(c),
+                }
+            }
+        }
+
+        res.into()
+    }
+
+    fn rule(&self, booktabs_rule: &'static str) -> &'static str {
+        match self.rules {
+            LatexRules::Hline => "\\hline",
+            LatexRules::Booktabs => booktabs_rule,
+        }
+    }
+
+    fn add_cells(result: &mut String, widths: &[usize], row: &[Cow<'_, str>]) {
+        let mut line = String::new();
+
+        for (index, width) in widths.iter().copied().enumerate() {
+            if index != 0 {
+                line.push_str(" & ");
+            }
+
+            let text = row.get(index).map(|c| Self::escape(c)).unwrap_or_default();
+            push_padded(&mut line, &text, width);
+        }
+
+        result.push_str(line.trim_end());
+        result.push_str(" \\\\\n");
+    }
+}
+
+impl Formatter for LatexFormatter {
+    fn format(&self, table: Table) -> String {
+        let widths = column_widths(&table, Self::escape);
+
+        if widths.is_empty() {
+            return String::new();
+        }
+
+        let spec = match self.rules {
+            LatexRules::Hline => format!("|{}", "l|".repeat(widths.len())),
+            LatexRules::Booktabs => "l".repeat(widths.len()),
+        };
+
+        let mut result = format!("\\begin{{tabular}}{{{spec}}}\n");
+        result.push_str(self.rule("\\toprule"));
+        result.push('\n');
+
+        if let Some(head) = table.titles() {
+            Self::add_cells(&mut result, &widths, head);
+            result.push_str(self.rule("\\midrule"));
+            result.push('\n');
+        }
+
+        for (index, row) in table.rows().enumerate() {
+            if index != 0 && self.rules == LatexRules::Hline {
+                result.push_str("\\hline\n");
+            }
+
+            Self::add_cells(&mut result, &widths, row);
+        }
+
+        result.push_str(self.rule("\\bottomrule"));
+        result.push_str("\n\\end{tabular}\n");
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Formatter, LatexFormatter, LatexRules, Table};
+
+    #[test]
+    fn latex_escapes() {
+        assert_eq!(
+            LatexFormatter::escape("50% of $5 & #1_{a} ~^\\"),
+            "50\\% of \\$5 \\& \\#1\\_\\{a\\} \\textasciitilde{}\\textasciicircum{}\\textbackslash{}"
+        );
+    }
+
+    #[test]
+    fn latex_leading_bracket() {
+        let t = Table::with_text_and_separator("a,b\n[1],[2]\n", ",", false, true);
+
+        assert_eq!(
+            LatexFormatter::new(LatexRules::Booktabs).format(t),
+            "\\begin{tabular}{ll}\n\
+             \\toprule\n\
+             a     & b \\\\\n\
+             {[}1] & {[}2] \\\\\n\
+             \\bottomrule\n\
+             \\end{tabular}\n"
+        );
+    }
+
+    #[test]
+    fn latex_booktabs() {
+        let t = Table::with_text_and_separator("Item,Qty\nApples,10\nPears,5\n", ",", true, true);
+
+        assert_eq!(
+            LatexFormatter::new(LatexRules::Booktabs).format(t),
+            "\\begin{tabular}{ll}\n\
+             \\toprule\n\
+             Item   & Qty \\\\\n\
+             \\midrule\n\
+             Apples & 10 \\\\\n\
+             Pears  & 5 \\\\\n\
+             \\bottomrule\n\
+             \\end{tabular}\n"
+        );
+    }
+
+    #[test]
+    fn latex_hline() {
+        let t = Table::with_text_and_separator("Item,Qty\nApples,10\nPears,5\n", ",", true, true);
+
+        assert_eq!(
+            LatexFormatter::new(LatexRules::Hline).format(t),
+            "\\begin{tabular}{|l|l|}\n\
+             \\hline\n\
+             Item   & Qty \\\\\n\
+             \\hline\n\
+             Apples & 10 \\\\\n\
+             \\hline\n\
+             Pears  & 5 \\\\\n\
+             \\hline\n\
+             \\end{tabular}\n"
+        );
+    }
+
+    #[test]
+    fn latex_empty() {
+        for rules in [LatexRules::Hline, LatexRules::Booktabs] {
+            let t = Table::with_text_and_separator("", ",", true, true);
+
+            assert_eq!(LatexFormatter::new(rules).format(t), "");
+        }
+    }
+
+    #[test]
+    fn latex_hline_no_titles() {
+        let t = Table::with_text_and_separator("a,b\n", ",", false, true);
+
+        assert_eq!(
+            LatexFormatter::new(LatexRules::Hline).format(t),
+            "\\begin{tabular}{|l|l|}\n\\hline\na & b \\\\\n\\hline\n\\end{tabular}\n"
+        );
+    }
+}
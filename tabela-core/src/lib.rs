@@ -0,0 +1,100 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+//! The conversion rules of [Tabëla](https://github.com/xanathar/tabela): reading
+//! tables from separated values, text aligned in columns, Markdown or HTML, and
+//! formatting them as Markdown, HTML, CSV, LaTeX, JSON, SQL and more.
+//!
+//! ```
+//! use tabela_core::{Formatter, MarkdownEscaping, MarkdownFormatter, Table};
+//!
+//! let table = Table::with_text_and_separator("a\tb\nx\t1\n", "\t", true, true);
+//! let markdown = MarkdownFormatter::new(MarkdownEscaping::Markup).format(table);
+//!
+//! assert_eq!(markdown, " a | b \n---|--:\n x | 1 \n");
+//! ```
+//!
+//! Diagnostics are logged through the [`log`](https://docs.rs/log) facade.
+
+#![warn(missing_docs)]
+
+mod alignment;
+mod asciidoc_formatter;
+mod csv_formatter;
+mod detect_separator;
+mod html_formatter;
+mod html_reader;
+mod jira_formatter;
+mod json_formatter;
+mod latex_formatter;
+mod markdown_formatter;
+mod markdown_reader;
+mod mediawiki_formatter;
+mod numbers;
+mod org_formatter;
+mod rst_formatter;
+mod split_aligned;
+mod split_line;
+mod split_records;
+mod sql_formatter;
+mod table;
+mod text_formatter;
+mod toml_formatter;
+mod values;
+mod widths;
+mod yaml_formatter;
+
+pub use alignment::Alignment;
+pub use asciidoc_formatter::AsciiDocFormatter;
+pub use csv_formatter::{CsvFormatter, Quoting};
+pub use detect_separator::detect_separator;
+pub use html_formatter::HtmlFormatter;
+pub use jira_formatter::JiraFormatter;
+pub use json_formatter::{JsonFormatter, JsonLayout, JsonValues};
+pub use latex_formatter::{LatexFormatter, LatexRules};
+pub use markdown_formatter::{MarkdownEscaping, MarkdownFormatter};
+pub use mediawiki_formatter::MediaWikiFormatter;
+pub use org_formatter::OrgFormatter;
+pub use rst_formatter::{RstGridFormatter, RstSimpleFormatter};
+pub use sql_formatter::{SqlDialect, SqlFormatter, SqlStatements};
+pub use table::Table;
+pub use text_formatter::{BoxStyle, TextFormatter};
+pub use toml_formatter::TomlFormatter;
+pub use yaml_formatter::YamlFormatter;
+
+use html_reader::read_html;
+use markdown_reader::read_markdown;
+use numbers::is_number;
+use split_aligned::split_aligned;
+use split_line::split_line;
+use split_records::split_records;
+use values::{Value, push_quoted};
+use widths::{column_widths, push_padded, text_width};
+
+/// Turns a table into text in some format.
+pub trait Formatter {
+    /// Formats the whole table, titles included.
+    fn format(&self, table: Table) -> String;
+}
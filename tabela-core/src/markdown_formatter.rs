@@ -0,0 +1,255 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Alignment, Formatter, Table, column_widths, text_width};
+use std::borrow::Cow;
+
+/// Which characters of the cells are escaped in Markdown. Pipes are escaped
+/// either way, as they would break the table.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MarkdownEscaping {
+    /// Characters which would style the text (like `*` or `_`) too
+    Markup,
+    /// Only pipes, so that cells written in Markdown keep their formatting
+    PipesOnly,
+}
+
+/// Formats tables as GitHub-flavored Markdown pipe tables, aligned with
+/// spaces so that they're readable as plain text too.
+pub struct MarkdownFormatter {
+    escaping: MarkdownEscaping,
+}
+
+impl MarkdownFormatter {
+    /// Creates a formatter escaping cells according to `escaping`.
+    pub const fn new(escaping: MarkdownEscaping) -> Self {
+        Self { escaping }
+    }
+
+    fn cell_text<'c>(&self, cell: &'c str) -> Cow<'c, str> {
+        let cell = cell.trim();
+
+        let escape_markup = self.escaping == MarkdownEscaping::Markup;
+
+        let needs_escaping = if escape_markup {
+            cell.contains([
+                '|', '\\', '*', '_', '`', '[', ']', '<', '>', '~', '\n', '\r',
+            ])
+        } else {
+            cell.contains(['|', '\n', '\r'])
+        };
+
+        if !needs_escaping {
+            return cell.into();
+        }
+
+        let mut res = String::with_capacity(cell.len() + 8);
+
+        for (index, line) in cell.lines().enumerate() {
+            if index != 0 {
+                res.push_str("<br>");
+            }
+
+            let mut chars = line.chars().peekable();
+
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '>' | '~' if escape_markup => {
+                        res.push('\\');
+                        res.push(c);
+                    }
+                    // Already escaped pipes are kept as they are
+                    '\\' if chars.peek() == Some(&'|') => {
+                        res.push(c);
+                        res.extend(chars.next());
+                    }
+                    '|' => res.push_str("\\|"),
+                    c => res.push(c),
+                }
+            }
+        }
+
+        res.into()
+    }
+
+    fn add_cells(
+        &self,
+        result: &mut String,
+        widths: &[usize],
+        alignments: &[Alignment],
+        row: &[Cow<'_, str>],
+    ) {
+        for (index, width) in widths.iter().copied().enumerate() {
+            if index != 0 {
+                result.push('|');
+            }
+
+            let s = row
+                .get(index)
+                .map(|c| self.cell_text(c))
+                .unwrap_or_default();
+            let padding = width.saturating_sub(text_width(&s));
+
+            let before = match alignments.get(index) {
+                Some(Alignment::Right) => padding,
+                Some(Alignment::Center) => padding / 2,
+                _ => 0,
+            };
+
+            for _ in 0..=before {
+                result.push(' ');
+            }
+
+            result.push_str(&s);
+
+            for _ in before..=padding {
+                result.push(' ');
+            }
+        }
+        result.push('\n');
+    }
+
+    fn add_dashes(result: &mut String, widths: &[usize], alignments: &[Alignment]) {
+        for (index, width) in widths.iter().copied().enumerate() {
+            if index != 0 {
+                result.push('|');
+            }
+
+            let (left, right) = match alignments.get(index) {
+                Some(Alignment::Left) => (':', '-'),
+                Some(Alignment::Center) => (':', ':'),
+                Some(Alignment::Right) => ('-', ':'),
+                _ => ('-', '-'),
+            };
+
+            result.push(left);
+            for _ in 0..width {
+                result.push('-');
+            }
+            result.push(right);
+        }
+        result.push('\n');
+    }
+}
+
+impl Formatter for MarkdownFormatter {
+    fn format(&self, table: Table) -> String {
+        let widths = column_widths(&table, |c| self.cell_text(c));
+        let alignments = table.alignments();
+
+        let mut result = String::new();
+
+        if let Some(head) = table.titles() {
+            self.add_cells(&mut result, &widths, &alignments, head);
+            Self::add_dashes(&mut result, &widths, &alignments);
+        }
+
+        for row in table.rows() {
+            self.add_cells(&mut result, &widths, &alignments, row);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Formatter, MarkdownEscaping, MarkdownFormatter, Table};
+
+    #[test]
+    fn markdown_table() {
+        let t = Table::with_text_and_separator("a,bb\nxyz,\"1\n2\"\n", ",", true, true);
+
+        assert_eq!(
+            MarkdownFormatter::new(MarkdownEscaping::Markup).format(t),
+            " a   | bb     \n-----|--------\n xyz | 1<br>2 \n"
+        );
+    }
+
+    #[test]
+    fn markdown_escapes() {
+        let escaping = MarkdownFormatter::new(MarkdownEscaping::Markup);
+        let passthrough = MarkdownFormatter::new(MarkdownEscaping::PipesOnly);
+
+        assert_eq!(
+            escaping.cell_text(" a|b *c* _d_ `e` [f](g) <h> ~i~ \\j "),
+            "a\\|b \\*c\\* \\_d\\_ \\`e\\` \\[f\\](g) \\<h\\> \\~i\\~ \\\\j"
+        );
+        assert_eq!(
+            passthrough.cell_text("a|b \\| **c**\n[d](e)"),
+            "a\\|b \\| **c**<br>[d](e)"
+        );
+    }
+
+    #[test]
+    fn markdown_wide_characters() {
+        let t = Table::with_text_and_separator(
+            "city,country\n東京,🇯🇵\nTorino,Italia\ne\u{301}te\u{301},x\n",
+            ",",
+            true,
+            true,
+        );
+
+        assert_eq!(
+            MarkdownFormatter::new(MarkdownEscaping::Markup).format(t),
+            " city   | country \n\
+             --------|---------\n\
+             \x20東京   | 🇯🇵      \n\
+             \x20Torino | Italia  \n\
+             \x20e\u{301}te\u{301}    | x       \n"
+        );
+    }
+
+    #[test]
+    fn markdown_alignments() {
+        let t = Table::with_markdown_text(
+            "| a | b | c |\n|:-:|:--|---|\n| xyz | x | 1 |\n| x | x | 100 |\n",
+            true,
+        );
+
+        assert_eq!(
+            MarkdownFormatter::new(MarkdownEscaping::Markup).format(t),
+            "  a  | b |   c \n\
+             :---:|:--|----:\n\
+             \x20xyz | x |   1 \n\
+             \x20 x  | x | 100 \n"
+        );
+    }
+
+    #[test]
+    fn markdown_round_trip() {
+        let text = " name     | note   | qty \n\
+                    ----------|--------|----:\n\
+                    \x20**bold** | a\\|b   |   1 \n\
+                    \x20`code`   | [x](y) |  10 \n";
+
+        let t = Table::with_markdown_text(text, true);
+
+        assert_eq!(
+            MarkdownFormatter::new(MarkdownEscaping::PipesOnly).format(t),
+            text
+        );
+    }
+}
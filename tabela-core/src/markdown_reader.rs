@@ -0,0 +1,268 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::Alignment;
+use std::borrow::Cow;
+
+/// A Markdown pipe table, as read from text.
+pub struct MarkdownTable<'a> {
+    pub head: Vec<Cow<'a, str>>,
+    pub rows: Vec<Vec<Cow<'a, str>>>,
+    pub alignments: Vec<Alignment>,
+}
+
+/// Reads a Markdown pipe table.
+///
+/// Lines without any `|` (like the text around a table copied from a README)
+/// are ignored; leading and trailing pipes are optional and `\|` stands for a
+/// pipe inside a cell. Titles are the row right before the dashes/colons
+/// delimiter row, and are empty if the table has no such row; lines with pipes
+/// before the titles are prose, not part of the table. After the delimiter
+/// row, the table ends at the first blank line or line without pipes, so the
+/// text after it (and any other table) is ignored too. Later rows of dashes
+/// (like `| - | - |` for "n/a") are data. Alignments come from the colons of
+/// the delimiter row, and are empty without one.
+pub fn read_markdown(text: &str) -> MarkdownTable<'_> {
+    let mut head = Vec::new();
+    let mut rows: Vec<Vec<Cow<'_, str>>> = Vec::new();
+    let mut alignments = Vec::new();
+    let mut has_delimiter = false;
+    let mut follows_row = false;
+
+    for line in text.lines() {
+        let Some(cells) = split_row(line) else {
+            if has_delimiter {
+                break;
+            }
+            follows_row = false;
+            continue;
+        };
+
+        let is_delimiter = !has_delimiter
+            && follows_row
+            && rows.last().is_some_and(|row| row.len() == cells.len())
+            && is_delimiter_row(&cells);
+
+        if is_delimiter {
+            head = rows.pop().unwrap_or_default();
+            rows.clear();
+            alignments = cells.iter().map(|c| alignment(c)).collect();
+            has_delimiter = true;
+            continue;
+        }
+
+        rows.push(cells);
+        follows_row = true;
+    }
+
+    MarkdownTable {
+        head,
+        rows,
+        alignments,
+    }
+}
+
+fn split_row(line: &str) -> Option<Vec<Cow<'_, str>>> {
+    let line = line.trim();
+    let mut cells = Vec::new();
+    let mut start = 0;
+    let mut has_escapes = false;
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((index, chr)) = chars.next() {
+        if chr == '\\' {
+            if chars.next_if(|(_, c)| *c == '|').is_some() {
+                has_escapes = true;
+            }
+        } else if chr == '|' {
+            cells.push(cell(&line[start..index], has_escapes));
+            start = index + 1;
+            has_escapes = false;
+        }
+    }
+
+    if cells.is_empty() {
+        return None;
+    }
+
+    if start < line.len() {
+        cells.push(cell(&line[start..], has_escapes));
+    }
+
+    if line.starts_with('|') {
+        cells.remove(0);
+    }
+
+    Some(cells)
+}
+
+fn cell(s: &str, has_escapes: bool) -> Cow<'_, str> {
+    let s = s.trim();
+
+    if has_escapes {
+        s.replace("\\|", "|").into()
+    } else {
+        s.into()
+    }
+}
+
+fn is_delimiter_row(cells: &[Cow<'_, str>]) -> bool {
+    !cells.is_empty()
+        && cells.iter().all(|c| {
+            let c = c.strip_prefix(':').unwrap_or(c);
+            let c = c.strip_suffix(':').unwrap_or(c);
+            !c.is_empty() && c.chars().all(|chr| chr == '-')
+        })
+}
+
+fn alignment(delimiter: &str) -> Alignment {
+    match (delimiter.starts_with(':'), delimiter.ends_with(':')) {
+        (true, true) => Alignment::Center,
+        (true, false) => Alignment::Left,
+        (false, true) => Alignment::Right,
+        (false, false) => Alignment::Auto,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_markdown;
+    use crate::Alignment;
+
+    fn test(text: &str, titles: &[&str], expected: &[&[&str]]) {
+        let table = read_markdown(text);
+
+        assert_eq!(table.head, titles);
+        assert_eq!(table.rows, expected);
+    }
+
+    #[test]
+    fn markdown_read_trivial() {
+        test(
+            "| a | b |\n|---|---|\n| 1 | 2 |\n| 3 | 4 |\n",
+            &["a", "b"],
+            &[&["1", "2"], &["3", "4"]],
+        );
+    }
+
+    #[test]
+    fn markdown_read_no_outer_pipes() {
+        test(
+            " a  | b  \n----|----\n 1  | 2  \n",
+            &["a", "b"],
+            &[&["1", "2"]],
+        );
+    }
+
+    #[test]
+    fn markdown_read_alignment_row() {
+        test(
+            "| a | b | c |\n|:--|:-:|--:|\n| 1 | 2 | 3 |\n",
+            &["a", "b", "c"],
+            &[&["1", "2", "3"]],
+        );
+
+        let table = read_markdown("| a | b | c | d |\n|:--|:-:|--:|---|\n");
+        assert_eq!(
+            table.alignments,
+            [
+                Alignment::Left,
+                Alignment::Center,
+                Alignment::Right,
+                Alignment::Auto
+            ]
+        );
+    }
+
+    #[test]
+    fn markdown_read_no_titles() {
+        test("| 1 | 2 |\n| 3 | 4 |\n", &[], &[&["1", "2"], &["3", "4"]]);
+    }
+
+    #[test]
+    fn markdown_read_escaped_pipes() {
+        test(
+            "| a | b |\n|---|---|\n| x \\| y | \\| |\n",
+            &["a", "b"],
+            &[&["x | y", "|"]],
+        );
+    }
+
+    #[test]
+    fn markdown_read_empty_cells() {
+        test(
+            "| a | b | c |\n|---|---|---|\n| | 2 | |\n",
+            &["a", "b", "c"],
+            &[&["", "2", ""]],
+        );
+    }
+
+    #[test]
+    fn markdown_read_surrounding_text() {
+        test(
+            "Some albums:\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\nThat's all.\n",
+            &["a", "b"],
+            &[&["1", "2"]],
+        );
+    }
+
+    #[test]
+    fn markdown_read_prose_with_pipes() {
+        test(
+            "Use a | b to pick one:\n\n| a | b |\n|---|---|\n| 1 | 2 |\n",
+            &["a", "b"],
+            &[&["1", "2"]],
+        );
+    }
+
+    #[test]
+    fn markdown_read_text_after_table() {
+        test(
+            "| a | b |\n|---|---|\n| 1 | 2 |\n\nRun `x | y` to see more.\n\n\
+             | c | d |\n|---|---|\n| 3 | 4 |\n",
+            &["a", "b"],
+            &[&["1", "2"]],
+        );
+    }
+
+    #[test]
+    fn markdown_read_dash_rows() {
+        test(
+            "| a | b |\n|---|---|\n| 1 | 2 |\n| - | - |\n|---|---|\n",
+            &["a", "b"],
+            &[&["1", "2"], &["-", "-"], &["---", "---"]],
+        );
+        test("| - | - |\n| 1 | 2 |\n", &[], &[&["-", "-"], &["1", "2"]]);
+    }
+
+    #[test]
+    fn markdown_read_keeps_inline_markup() {
+        test(
+            "| **a** | `b` |\n|---|---|\n| [x](y) | \\*z |\n",
+            &["**a**", "`b`"],
+            &[&["[x](y)", "\\*z"]],
+        );
+    }
+}
@@ -0,0 +1,114 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Formatter, Table};
+use std::borrow::Cow;
+
+/// Formats tables with the wikitext of MediaWiki, as a `wikitable` with
+/// one cell per line.
+pub struct MediaWikiFormatter;
+
+impl MediaWikiFormatter {
+    fn escape(cell: &str) -> Cow<'_, str> {
+        let cell = cell.trim();
+
+        if !cell.contains(['|', '!', '\n', '\r']) {
+            return cell.into();
+        }
+
+        let mut res = String::with_capacity(cell.len() + 8);
+
+        for (index, line) in cell.lines().enumerate() {
+            if index != 0 {
+                res.push_str("<br>");
+            }
+
+            for c in line.trim().chars() {
+                match c {
+                    '|' => res.push_str("&#124;"),
+                    '!' => res.push_str("&#33;"),
+                    c => res.push(c),
+                }
+            }
+        }
+
+        res.into()
+    }
+
+    fn format_row(result: &mut String, columns: usize, row: &[Cow<'_, str>], marker: &str) {
+        for index in 0..columns {
+            let text = Self::escape(row.get(index).map(|c| c.as_ref()).unwrap_or_default());
+            if text.is_empty() {
+                result.push_str(marker);
+            } else {
+                result.push_str(&format!("{marker} {text}"));
+            }
+            result.push('\n');
+        }
+    }
+}
+
+impl Formatter for MediaWikiFormatter {
+    fn format(&self, table: Table) -> String {
+        let columns = table.columns();
+        let mut result = String::new();
+
+        result.push_str("{| class=\"wikitable\"\n");
+
+        if let Some(head) = table.titles() {
+            Self::format_row(&mut result, columns, head, "!");
+        }
+
+        for row in table.rows() {
+            result.push_str("|-\n");
+            Self::format_row(&mut result, columns, row, "|");
+        }
+
+        result.push_str("|}\n");
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Formatter, MediaWikiFormatter, Table};
+
+    #[test]
+    fn mediawiki_escapes() {
+        assert_eq!(MediaWikiFormatter::escape("a|b!!c"), "a&#124;b&#33;&#33;c");
+        assert_eq!(MediaWikiFormatter::escape(" x\ny "), "x<br>y");
+    }
+
+    #[test]
+    fn mediawiki_table() {
+        let t = Table::with_text_and_separator("a,b\n1,\n", ",", true, true);
+
+        assert_eq!(
+            MediaWikiFormatter.format(t),
+            "{| class=\"wikitable\"\n! a\n! b\n|-\n| 1\n|\n|}\n"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+/// Returns whether a cell is a plain decimal number, like `42`, `-3.14` or
+/// `1e-3`. Things that Rust would parse as a float but nobody would write in
+/// a table (like `inf` or `NaN`) are not numbers.
+pub fn is_number(s: &str) -> bool {
+    let s = s.strip_prefix(['-', '+']).unwrap_or(s);
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(e) => (&s[..e], Some(&s[e + 1..])),
+        None => (s, None),
+    };
+
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, fraction),
+        None => (mantissa, ""),
+    };
+
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+
+    (!integer.is_empty() || !fraction.is_empty())
+        && all_digits(integer)
+        && all_digits(fraction)
+        && exponent.is_none_or(|e| {
+            let e = e.strip_prefix(['-', '+']).unwrap_or(e);
+            !e.is_empty() && all_digits(e)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_number;
+
+    #[test]
+    fn numbers() {
+        for n in ["0", "42", "-3", "+3", "3.14", ".5", "5.", "1e3", "-1.5E-3"] {
+            assert!(is_number(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn not_numbers() {
+        for n in [
+            "", "-", ".", "e3", "1e", "1,5", "1.2.3", "inf", "NaN", "0x10", " 1",
+        ] {
+            assert!(!is_number(n), "{n}");
+        }
+    }
+}
@@ -0,0 +1,147 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Alignment, Formatter, Table, column_widths, push_padded, text_width};
+use std::borrow::Cow;
+
+/// Formats tables for Emacs org-mode, aligned the way org-mode itself aligns
+/// them: right-aligned columns (like the numeric ones) are padded on the left,
+/// and everything else on the right.
+pub struct OrgFormatter;
+
+impl OrgFormatter {
+    fn cell_text(cell: &str) -> Cow<'_, str> {
+        let cell = cell.trim();
+
+        if !cell.contains(['|', '\n', '\r']) {
+            return cell.into();
+        }
+
+        // Org tables can't contain line breaks, and `\vert{}` is the entity
+        // org-mode itself suggests for a literal pipe in a table
+        cell.lines()
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace('|', "\\vert{}")
+            .into()
+    }
+
+    fn add_cells(
+        result: &mut String,
+        widths: &[usize],
+        alignments: &[Alignment],
+        row: &[Cow<'_, str>],
+    ) {
+        result.push('|');
+        for (index, width) in widths.iter().copied().enumerate() {
+            let text = row
+                .get(index)
+                .map(|c| Self::cell_text(c))
+                .unwrap_or_default();
+
+            result.push(' ');
+            if alignments.get(index) == Some(&Alignment::Right) {
+                for _ in text_width(&text)..width {
+                    result.push(' ');
+                }
+                result.push_str(&text);
+            } else {
+                push_padded(result, &text, width);
+            }
+            result.push_str(" |");
+        }
+        result.push('\n');
+    }
+
+    fn add_hline(result: &mut String, widths: &[usize]) {
+        result.push('|');
+        for (index, width) in widths.iter().copied().enumerate() {
+            if index != 0 {
+                result.push('+');
+            }
+
+            for _ in 0..width + 2 {
+                result.push('-');
+            }
+        }
+        result.push_str("|\n");
+    }
+}
+
+impl Formatter for OrgFormatter {
+    fn format(&self, table: Table) -> String {
+        let widths = column_widths(&table, Self::cell_text);
+        let alignments = table.alignments();
+
+        let mut result = String::new();
+
+        if widths.is_empty() {
+            return result;
+        }
+
+        if let Some(head) = table.titles() {
+            Self::add_cells(&mut result, &widths, &alignments, head);
+            Self::add_hline(&mut result, &widths);
+        }
+
+        for row in table.rows() {
+            Self::add_cells(&mut result, &widths, &alignments, row);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Formatter, OrgFormatter, Table};
+
+    #[test]
+    fn org_table() {
+        let t = Table::with_text_and_separator("a,bb\n\"x|y\",\"1\n2\"\nccc\n", ",", true, true);
+
+        assert_eq!(
+            OrgFormatter.format(t),
+            "| a         | bb  |\n\
+             |-----------+-----|\n\
+             | x\\vert{}y | 1 2 |\n\
+             | ccc       |     |\n"
+        );
+    }
+
+    #[test]
+    fn org_numbers_right_aligned() {
+        let t = Table::with_text_and_separator("Item,Qty\nApples,10\nPears,5\n", ",", true, true);
+
+        assert_eq!(
+            OrgFormatter.format(t),
+            "| Item   | Qty |\n\
+             |--------+-----|\n\
+             | Apples |  10 |\n\
+             | Pears  |   5 |\n"
+        );
+    }
+}
@@ -0,0 +1,241 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Formatter, Table, column_widths, push_padded};
+use std::borrow::Cow;
+
+/// Formats tables as reStructuredText grid tables, where cells with line
+/// breaks span multiple lines.
+pub struct RstGridFormatter;
+
+/// Formats tables as reStructuredText simple tables, which are lighter to
+/// read but can't contain line breaks.
+pub struct RstSimpleFormatter;
+
+impl RstGridFormatter {
+    fn cell_text(cell: &str) -> Cow<'_, str> {
+        cell.trim().into()
+    }
+
+    fn add_border(result: &mut String, widths: &[usize], fill: char) {
+        result.push('+');
+        for width in widths.iter().copied() {
+            for _ in 0..width + 2 {
+                result.push(fill);
+            }
+            result.push('+');
+        }
+        result.push('\n');
+    }
+
+    fn add_cells(result: &mut String, widths: &[usize], row: &[Cow<'_, str>]) {
+        let cells = (0..widths.len())
+            .map(|index| {
+                row.get(index)
+                    .map(|c| c.trim().lines().map(str::trim).collect::<Vec<_>>())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        let height = cells.iter().map(|c| c.len()).max().unwrap_or(0).max(1);
+
+        for line in 0..height {
+            result.push('|');
+            for (cell, width) in cells.iter().zip(widths.iter().copied()) {
+                result.push(' ');
+                push_padded(result, cell.get(line).copied().unwrap_or_default(), width);
+                result.push_str(" |");
+            }
+            result.push('\n');
+        }
+    }
+}
+
+impl Formatter for RstGridFormatter {
+    fn format(&self, table: Table) -> String {
+        let widths = column_widths(&table, Self::cell_text);
+
+        let mut result = String::new();
+
+        if widths.is_empty() {
+            return result;
+        }
+
+        Self::add_border(&mut result, &widths, '-');
+
+        if let Some(head) = table.titles() {
+            Self::add_cells(&mut result, &widths, head);
+            Self::add_border(&mut result, &widths, '=');
+        }
+
+        for row in table.rows() {
+            Self::add_cells(&mut result, &widths, row);
+            Self::add_border(&mut result, &widths, '-');
+        }
+
+        result
+    }
+}
+
+impl RstSimpleFormatter {
+    fn cell_text(cell: &str) -> Cow<'_, str> {
+        let cell = cell.trim();
+
+        if cell.contains('\n') {
+            cell.lines()
+                .map(str::trim)
+                .collect::<Vec<_>>()
+                .join(" ")
+                .into()
+        } else {
+            cell.into()
+        }
+    }
+
+    fn add_rule(result: &mut String, widths: &[usize]) {
+        for (index, width) in widths.iter().copied().enumerate() {
+            if index != 0 {
+                result.push_str("  ");
+            }
+
+            for _ in 0..width.max(1) {
+                result.push('=');
+            }
+        }
+        result.push('\n');
+    }
+
+    fn add_cells(result: &mut String, widths: &[usize], row: &[Cow<'_, str>]) {
+        let mut line = String::new();
+
+        for (index, width) in widths.iter().copied().enumerate() {
+            if index != 0 {
+                line.push_str("  ");
+            }
+
+            let text = row
+                .get(index)
+                .map(|c| Self::cell_text(c))
+                .unwrap_or_default();
+
+            // A blank first column would make the row a continuation of the
+            // previous one, so an empty comment stands for the empty cell
+            let text = if index == 0 && text.is_empty() {
+                "..".into()
+            } else {
+                text
+            };
+
+            push_padded(&mut line, &text, width.max(1));
+        }
+
+        result.push_str(line.trim_end());
+        result.push('\n');
+    }
+}
+
+impl Formatter for RstSimpleFormatter {
+    fn format(&self, table: Table) -> String {
+        let mut widths = column_widths(&table, Self::cell_text);
+
+        let mut result = String::new();
+
+        if widths.is_empty() {
+            return result;
+        }
+
+        // Leave room for the ".." of empty cells in the first column
+        widths[0] = widths[0].max(2);
+
+        Self::add_rule(&mut result, &widths);
+
+        if let Some(head) = table.titles() {
+            Self::add_cells(&mut result, &widths, head);
+            Self::add_rule(&mut result, &widths);
+        }
+
+        for row in table.rows() {
+            Self::add_cells(&mut result, &widths, row);
+        }
+
+        Self::add_rule(&mut result, &widths);
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Formatter, RstGridFormatter, RstSimpleFormatter, Table};
+
+    #[test]
+    fn rst_grid() {
+        let t = Table::with_text_and_separator("a,bb\n\"1\n22\",\n", ",", true, true);
+
+        assert_eq!(
+            RstGridFormatter.format(t),
+            "+----+----+\n\
+             | a  | bb |\n\
+             +====+====+\n\
+             | 1  |    |\n\
+             | 22 |    |\n\
+             +----+----+\n"
+        );
+    }
+
+    #[test]
+    fn rst_grid_no_titles() {
+        let t = Table::with_text_and_separator("a,b\n", ",", false, true);
+
+        assert_eq!(
+            RstGridFormatter.format(t),
+            "+---+---+\n| a | b |\n+---+---+\n"
+        );
+    }
+
+    #[test]
+    fn rst_simple() {
+        let t = Table::with_text_and_separator("a,bb\n,\"x\ny\"\nccc,z\n", ",", true, true);
+
+        assert_eq!(
+            RstSimpleFormatter.format(t),
+            "===  ===\n\
+             a    bb\n\
+             ===  ===\n\
+             ..   x y\n\
+             ccc  z\n\
+             ===  ===\n"
+        );
+    }
+
+    #[test]
+    fn rst_empty() {
+        let t = Table::with_text_and_separator("", ",", true, true);
+        assert_eq!(RstGridFormatter.format(t), "");
+
+        let t = Table::with_text_and_separator("", ",", true, true);
+        assert_eq!(RstSimpleFormatter.format(t), "");
+    }
+}
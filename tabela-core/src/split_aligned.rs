@@ -0,0 +1,272 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::text_width;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Splits text whose columns are aligned with runs of spaces, like the output
+/// of `ps`, `df -h`, `docker ps` or `kubectl get`, into rows of cells.
+///
+/// A column boundary is a position which is blank in every line, with text
+/// after it in at least two lines: this way right-aligned numbers are kept
+/// together, and a title made of two words over short values (like the
+/// "Mounted on" of `df`) is not split in two. When `has_head` is set, columns
+/// without any title are merged into the previous one, so that values with
+/// spaces in them (like commands with arguments) stay in one cell. Positions
+/// are display columns, so wide characters (like CJK or emoji) take two.
+pub fn split_aligned(text: &str, has_head: bool) -> Vec<Vec<&str>> {
+    let lines = text
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .collect::<Vec<_>>();
+    let chars = lines.iter().map(|l| columns(l)).collect::<Vec<_>>();
+
+    let starts = column_starts(&chars, has_head);
+
+    lines
+        .iter()
+        .zip(chars.iter())
+        .map(|(line, chars)| split_at_columns(line, chars, &starts))
+        .collect()
+}
+
+const TAB_WIDTH: usize = 8;
+
+/// The byte offset and the character at each display column of a line; the
+/// columns taken by a wide character all point to it, and tabs are expanded
+/// to the next multiple of 8 columns like a terminal does.
+fn columns(line: &str) -> Vec<(usize, char)> {
+    let mut columns = Vec::with_capacity(line.len());
+
+    for (offset, grapheme) in line.grapheme_indices(true) {
+        if grapheme == "\t" {
+            let stop = (columns.len() / TAB_WIDTH + 1) * TAB_WIDTH;
+            columns.resize(stop, (offset, ' '));
+            continue;
+        }
+
+        let chr = grapheme.chars().next().unwrap_or(' ');
+        for _ in 0..text_width(grapheme) {
+            columns.push((offset, chr));
+        }
+    }
+
+    columns
+}
+
+fn column_starts(lines: &[Vec<(usize, char)>], has_head: bool) -> Vec<usize> {
+    let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+    let min_support = lines.len().min(2);
+
+    // Position of the last non-blank character of each line
+    let ends = lines
+        .iter()
+        .map(|l| l.iter().rposition(|(_, c)| !c.is_whitespace()))
+        .collect::<Vec<_>>();
+
+    let is_gap = (0..width)
+        .map(|pos| {
+            let blank = lines
+                .iter()
+                .all(|l| l.get(pos).is_none_or(|(_, c)| c.is_whitespace()));
+
+            blank && ends.iter().filter(|e| e.is_some_and(|e| e > pos)).count() >= min_support
+        })
+        .collect::<Vec<_>>();
+
+    let mut starts = (0..width)
+        .filter(|&pos| !is_gap[pos] && (pos == 0 || is_gap[pos - 1]))
+        .collect::<Vec<_>>();
+
+    if let Some(head) = lines.first().filter(|_| has_head) {
+        let mut merged: Vec<usize> = Vec::with_capacity(starts.len());
+
+        for (idx, start) in starts.iter().copied().enumerate() {
+            let end = starts.get(idx + 1).copied().unwrap_or(width);
+            let has_title = head
+                .iter()
+                .take(end)
+                .skip(start)
+                .any(|(_, c)| !c.is_whitespace());
+
+            if has_title || merged.is_empty() {
+                merged.push(start);
+            }
+        }
+
+        starts = merged;
+    }
+
+    starts
+}
+
+fn split_at_columns<'a>(line: &'a str, chars: &[(usize, char)], starts: &[usize]) -> Vec<&'a str> {
+    let byte_offset = |pos: usize| chars.get(pos).map(|(o, _)| *o).unwrap_or(line.len());
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(idx, &start)| {
+            let end = starts
+                .get(idx + 1)
+                .map(|&end| byte_offset(end))
+                .unwrap_or(line.len());
+
+            line[byte_offset(start)..end].trim()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_aligned;
+
+    fn test(text: &str, has_head: bool, expected: &[&[&str]]) {
+        assert_eq!(split_aligned(text, has_head), expected);
+    }
+
+    #[test]
+    fn aligned_trivial() {
+        test(
+            "NAME   AGE\nfoo    1\nbarbaz 22\n",
+            true,
+            &[&["NAME", "AGE"], &["foo", "1"], &["barbaz", "22"]],
+        );
+    }
+
+    #[test]
+    fn aligned_tabs() {
+        test(
+            "NAME\tAGE\nfoo\t1\nbarbaz  22\n",
+            true,
+            &[&["NAME", "AGE"], &["foo", "1"], &["barbaz", "22"]],
+        );
+    }
+
+    #[test]
+    fn aligned_right_aligned_numbers() {
+        test(
+            "USER     PID COMMAND\nroot       1 init\nmarco  12345 bash\n",
+            true,
+            &[
+                &["USER", "PID", "COMMAND"],
+                &["root", "1", "init"],
+                &["marco", "12345", "bash"],
+            ],
+        );
+    }
+
+    #[test]
+    fn aligned_title_with_spaces() {
+        test(
+            "Filesystem  Size  Mounted on\n/dev/sda1    20G  /\n/dev/sda2   100G  /boot\n",
+            true,
+            &[
+                &["Filesystem", "Size", "Mounted on"],
+                &["/dev/sda1", "20G", "/"],
+                &["/dev/sda2", "100G", "/boot"],
+            ],
+        );
+    }
+
+    #[test]
+    fn aligned_values_with_spaces() {
+        test(
+            "CREATED       PORTS\n2 hours ago   80/tcp\n3 hours ago   443/tcp\n",
+            true,
+            &[
+                &["CREATED", "PORTS"],
+                &["2 hours ago", "80/tcp"],
+                &["3 hours ago", "443/tcp"],
+            ],
+        );
+    }
+
+    #[test]
+    fn aligned_last_column_with_spaces() {
+        test(
+            "PID COMMAND\n  1 /sbin/init splash\n  2 /bin/sh -c true\n",
+            true,
+            &[
+                &["PID", "COMMAND"],
+                &["1", "/sbin/init splash"],
+                &["2", "/bin/sh -c true"],
+            ],
+        );
+    }
+
+    #[test]
+    fn aligned_empty_cells() {
+        test(
+            "NAME  PORTS  STATUS\nweb   80     up\ndb           down\n",
+            true,
+            &[
+                &["NAME", "PORTS", "STATUS"],
+                &["web", "80", "up"],
+                &["db", "", "down"],
+            ],
+        );
+    }
+
+    #[test]
+    fn aligned_no_titles() {
+        test(
+            "a  1 x\nbb 2 y\n",
+            false,
+            &[&["a", "1", "x"], &["bb", "2", "y"]],
+        );
+    }
+
+    #[test]
+    fn aligned_unicode() {
+        test(
+            "città  abitanti\nTorino 850000\nAsti   75000\n",
+            true,
+            &[
+                &["città", "abitanti"],
+                &["Torino", "850000"],
+                &["Asti", "75000"],
+            ],
+        );
+    }
+
+    #[test]
+    fn aligned_wide_characters() {
+        test(
+            "NAMES     IMAGE  STATUS\n日本語    nginx  Up\n🚀rocket  redis  Exited\n",
+            true,
+            &[
+                &["NAMES", "IMAGE", "STATUS"],
+                &["日本語", "nginx", "Up"],
+                &["🚀rocket", "redis", "Exited"],
+            ],
+        );
+    }
+
+    #[test]
+    fn aligned_empty() {
+        test("\n\n", true, &[]);
+    }
+}
@@ -25,15 +25,15 @@
 
 use std::{borrow::Cow, str::CharIndices};
 
-pub struct SplitLine<'a> {
+pub struct SplitLine<'a, 's> {
     line: &'a str,
-    separator: char,
+    separator: &'s str,
     remove_quotes: bool,
     indices: CharIndices<'a>,
 }
 
-impl<'a> SplitLine<'a> {
-    fn new(line: &'a str, separator: char, remove_quotes: bool) -> Self {
+impl<'a, 's> SplitLine<'a, 's> {
+    fn new(line: &'a str, separator: &'s str, remove_quotes: bool) -> Self {
         Self {
             line,
             separator,
@@ -42,6 +42,18 @@ impl<'a> SplitLine<'a> {
         }
     }
 
+    fn is_separator_at(&self, index: usize) -> bool {
+        !self.separator.is_empty() && self.line[index..].starts_with(self.separator)
+    }
+
+    /// Consumes the rest of a (possibly multi-character) separator, given
+    /// the index of its first character.
+    fn skip_separator(&mut self, index: usize) {
+        while self.indices.offset() < index + self.separator.len() {
+            self.indices.next();
+        }
+    }
+
     fn is_quote(chr: char) -> bool {
         chr == '\'' || chr == '"'
     }
@@ -76,13 +88,14 @@ impl<'a> SplitLine<'a> {
     }
 }
 
-impl<'a> Iterator for SplitLine<'a> {
+impl<'a> Iterator for SplitLine<'a, '_> {
     type Item = Cow<'a, str>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let (start_offset, opening) = self.indices.next()?;
 
-        if opening == self.separator {
+        if self.is_separator_at(start_offset) {
+            self.skip_separator(start_offset);
             return Some("".into());
         }
 
@@ -96,7 +109,7 @@ impl<'a> Iterator for SplitLine<'a> {
             let mut last_quote_index = None;
             let mut has_escaped_quotes = false;
 
-            for (index, chr) in self.indices.by_ref() {
+            while let Some((index, chr)) = self.indices.next() {
                 if chr == opening {
                     if last_quote_index.is_none() {
                         last_quote_index = Some(index);
@@ -107,11 +120,13 @@ impl<'a> Iterator for SplitLine<'a> {
                     continue;
                 }
 
-                if chr == self.separator {
+                if self.is_separator_at(index) {
                     let Some(lqi) = last_quote_index else {
                         continue;
                     };
 
+                    self.skip_separator(index);
+
                     if self.remove_quotes {
                         return Self::unescape_quoted_str(
                             has_escaped_quotes,
@@ -143,8 +158,9 @@ impl<'a> Iterator for SplitLine<'a> {
                 ),
             }
         } else {
-            for (index, chr) in self.indices.by_ref() {
-                if chr == self.separator {
+            while let Some((index, _)) = self.indices.next() {
+                if self.is_separator_at(index) {
+                    self.skip_separator(index);
                     return Some(Cow::Borrowed(&self.line[start_offset..index]));
                 }
             }
@@ -154,7 +170,14 @@ impl<'a> Iterator for SplitLine<'a> {
     }
 }
 
-pub fn split_line(line: &'_ str, separator: char, remove_quotes: bool) -> SplitLine<'_> {
+/// Splits a record into its cells, which are separated by `separator` unless
+/// they're enclosed in quotes (double or single), where doubled quotes stand
+/// for one. With `remove_quotes`, the quotes enclosing a cell are removed.
+pub fn split_line<'a, 's>(
+    line: &'a str,
+    separator: &'s str,
+    remove_quotes: bool,
+) -> SplitLine<'a, 's> {
     SplitLine::new(line, separator, remove_quotes)
 }
 
@@ -163,7 +186,11 @@ mod tests {
     use super::split_line;
 
     fn test(line: &str, remove_quotes: bool, expected: &[&str]) {
-        let split = split_line(line, ',', remove_quotes)
+        test_with_separator(line, ",", remove_quotes, expected);
+    }
+
+    fn test_with_separator(line: &str, separator: &str, remove_quotes: bool, expected: &[&str]) {
+        let split = split_line(line, separator, remove_quotes)
             .map(|s| s.into_owned())
             .collect::<Vec<_>>();
 
@@ -301,4 +328,39 @@ mod tests {
         // we tolerate a missing third value
         test(",,", true, &["", ""]);
     }
+
+    #[test]
+    fn line_split_pipe() {
+        test_with_separator("Ciao|Hello|Hola", "|", true, &["Ciao", "Hello", "Hola"]);
+    }
+
+    #[test]
+    fn line_split_multichar() {
+        test_with_separator("Ciao||Hello||Hola", "||", true, &["Ciao", "Hello", "Hola"]);
+    }
+
+    #[test]
+    fn line_split_multichar_partial() {
+        test_with_separator("Ciao|Hello||Hola", "||", true, &["Ciao|Hello", "Hola"]);
+    }
+
+    #[test]
+    fn line_split_multichar_quoted() {
+        test_with_separator(
+            "\"Ciao - Hello\" - Hola",
+            " - ",
+            true,
+            &["Ciao - Hello", "Hola"],
+        );
+    }
+
+    #[test]
+    fn line_split_multichar_missing_field() {
+        test_with_separator("Ciao -  - Hola", " - ", true, &["Ciao", "", "Hola"]);
+    }
+
+    #[test]
+    fn line_split_empty_separator() {
+        test_with_separator("Ciao,Hola", "", true, &["Ciao,Hola"]);
+    }
 }
@@ -0,0 +1,196 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+/// Splits a text into records, as per RFC 4180: a line break inside a
+/// double-quoted field is part of the field and does not end the record.
+///
+/// Only double quotes can span multiple lines; single quotes are still
+/// handled by `split_line`, but never continue a record on the next line.
+/// If a quoted field is never closed, the record falls back to ending at
+/// the first line break, so that a stray quote does not swallow the rest
+/// of the text.
+pub struct SplitRecords<'a, 's> {
+    text: &'a str,
+    separator: &'s str,
+    offset: usize,
+}
+
+impl<'a, 's> SplitRecords<'a, 's> {
+    fn new(text: &'a str, separator: &'s str) -> Self {
+        Self {
+            text,
+            separator,
+            offset: 0,
+        }
+    }
+
+    fn record_len(&self, rest: &str) -> usize {
+        let mut in_quotes = false;
+        let mut at_field_start = true;
+        let mut after_quote = false;
+        let mut first_quoted_newline = None;
+        let mut separator_end = 0;
+
+        for (index, chr) in rest.char_indices() {
+            if index < separator_end {
+                continue;
+            }
+
+            if in_quotes {
+                if chr == '"' {
+                    in_quotes = false;
+                    after_quote = true;
+                } else if chr == '\n' && first_quoted_newline.is_none() {
+                    first_quoted_newline = Some(index);
+                }
+                continue;
+            }
+
+            if chr == '\n' {
+                return index;
+            }
+
+            if !self.separator.is_empty() && rest[index..].starts_with(self.separator) {
+                separator_end = index + self.separator.len();
+                at_field_start = true;
+                after_quote = false;
+                continue;
+            }
+
+            // A quote right after a closing quote is an escaped quote ("")
+            // and brings us back inside the quoted field.
+            if chr == '"' && (at_field_start || after_quote) {
+                in_quotes = true;
+            }
+
+            at_field_start = false;
+            after_quote = false;
+        }
+
+        match first_quoted_newline {
+            Some(index) if in_quotes => index,
+            _ => rest.len(),
+        }
+    }
+}
+
+impl<'a> Iterator for SplitRecords<'a, '_> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.text.len() {
+            return None;
+        }
+
+        let rest = &self.text[self.offset..];
+        let len = self.record_len(rest);
+        self.offset += len + 1;
+
+        let record = &rest[..len];
+        Some(record.strip_suffix('\r').unwrap_or(record))
+    }
+}
+
+/// Splits text into records (usually lines), keeping together the lines of
+/// cells enclosed in double quotes, as spreadsheets do for cells with line
+/// breaks.
+pub fn split_records<'a, 's>(text: &'a str, separator: &'s str) -> SplitRecords<'a, 's> {
+    SplitRecords::new(text, separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_records;
+
+    fn test(text: &str, expected: &[&str]) {
+        test_with_separator(text, ",", expected);
+    }
+
+    fn test_with_separator(text: &str, separator: &str, expected: &[&str]) {
+        let split = split_records(text, separator).collect::<Vec<_>>();
+
+        assert_eq!(split, expected);
+    }
+
+    #[test]
+    fn records_split_trivial() {
+        test("a,b\nc,d", &["a,b", "c,d"]);
+    }
+
+    #[test]
+    fn records_split_crlf() {
+        test("a,b\r\nc,d\r\n", &["a,b", "c,d"]);
+    }
+
+    #[test]
+    fn records_split_empty_lines() {
+        test("a,b\n\nc,d", &["a,b", "", "c,d"]);
+    }
+
+    #[test]
+    fn records_split_quoted_newline() {
+        test("a,\"multi\nline\",b\nc,d", &["a,\"multi\nline\",b", "c,d"]);
+    }
+
+    #[test]
+    fn records_split_quoted_crlf() {
+        test(
+            "a,\"multi\r\nline\"\r\nc,d",
+            &["a,\"multi\r\nline\"", "c,d"],
+        );
+    }
+
+    #[test]
+    fn records_split_escaped_quotes() {
+        test(
+            "a,\"say \"\"hi\nthere\"\"\",b\nc,d",
+            &["a,\"say \"\"hi\nthere\"\"\",b", "c,d"],
+        );
+    }
+
+    #[test]
+    fn records_split_quote_mid_field() {
+        test("a,b\"c\nd,e", &["a,b\"c", "d,e"]);
+    }
+
+    #[test]
+    fn records_split_single_quotes() {
+        test("a,'b\nc',d", &["a,'b", "c',d"]);
+    }
+
+    #[test]
+    fn records_split_unterminated_quote() {
+        test("a,\"b\nc,d\ne,f", &["a,\"b", "c,d", "e,f"]);
+    }
+
+    #[test]
+    fn records_split_multichar_separator() {
+        test_with_separator(
+            "a || \"b\nc\"\nd || e",
+            " || ",
+            &["a || \"b\nc\"", "d || e"],
+        );
+    }
+}
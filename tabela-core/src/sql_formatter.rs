@@ -0,0 +1,394 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Formatter, Table, Value};
+use std::borrow::Cow;
+
+/// The database the SQL is written for, which decides how identifiers are
+/// quoted, how string literals are escaped and the names of the types.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SqlDialect {
+    /// PostgreSQL
+    PostgreSql,
+    /// MySQL or MariaDB
+    MySql,
+    /// SQLite
+    Sqlite,
+}
+
+/// Which statements are written.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SqlStatements {
+    /// The `CREATE TABLE` for the rows, followed by the `INSERT`s
+    CreateAndInsert,
+    /// Only the `INSERT`s, for a table which already exists
+    InsertOnly,
+}
+
+/// The type of a column, inferred from all its non-empty cells.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ColumnType {
+    Integer,
+    BigInteger,
+    /// Integers too large for 64 bits, like some IDs or account numbers
+    Decimal,
+    Float,
+    Boolean,
+    Text,
+}
+
+impl ColumnType {
+    fn of(value: Value) -> Option<Self> {
+        match value {
+            Value::Null => None,
+            Value::Bool(_) => Some(ColumnType::Boolean),
+            Value::Number(n) if n.contains(['.', 'e', 'E']) => Some(ColumnType::Float),
+            Value::Number(n) => Some(match n.parse::<i64>() {
+                Ok(n) if i32::try_from(n).is_ok() => ColumnType::Integer,
+                Ok(_) => ColumnType::BigInteger,
+                Err(_) => ColumnType::Decimal,
+            }),
+            Value::Text(_) => Some(ColumnType::Text),
+        }
+    }
+
+    /// The narrowest type fitting the values of both types.
+    fn merge(self, other: Self) -> Self {
+        use ColumnType::*;
+
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Integer | BigInteger, Integer | BigInteger) => BigInteger,
+            (Integer | BigInteger | Decimal, Integer | BigInteger | Decimal) => Decimal,
+            // Floats would round the large integers
+            (Integer | BigInteger | Float, Integer | BigInteger | Float) => Float,
+            _ => Text,
+        }
+    }
+
+    fn name(self, dialect: SqlDialect) -> &'static str {
+        match (self, dialect) {
+            (ColumnType::Integer, _) => "INTEGER",
+            (ColumnType::BigInteger, SqlDialect::Sqlite) => "INTEGER",
+            (ColumnType::BigInteger, _) => "BIGINT",
+            (ColumnType::Decimal, SqlDialect::MySql) => "DECIMAL(65,0)",
+            (ColumnType::Decimal, _) => "NUMERIC",
+            (ColumnType::Float, SqlDialect::PostgreSql) => "DOUBLE PRECISION",
+            (ColumnType::Float, SqlDialect::MySql) => "DOUBLE",
+            (ColumnType::Float, SqlDialect::Sqlite) => "REAL",
+            (ColumnType::Boolean, _) => "BOOLEAN",
+            (ColumnType::Text, _) => "TEXT",
+        }
+    }
+}
+
+/// Formats tables as SQL `INSERT` statements, optionally preceded by the
+/// `CREATE TABLE` for them. Columns are named after the titles, and empty
+/// cells are `NULL`.
+pub struct SqlFormatter {
+    dialect: SqlDialect,
+    table_name: Cow<'static, str>,
+    statements: SqlStatements,
+}
+
+impl SqlFormatter {
+    /// Creates a formatter writing the `statements` for `dialect` into the
+    /// table named `table_name`.
+    pub fn new(
+        dialect: SqlDialect,
+        table_name: impl Into<Cow<'static, str>>,
+        statements: SqlStatements,
+    ) -> Self {
+        Self {
+            dialect,
+            table_name: table_name.into(),
+            statements,
+        }
+    }
+
+    fn push_identifier(&self, result: &mut String, name: &str) {
+        let quote = match self.dialect {
+            SqlDialect::MySql => '`',
+            SqlDialect::PostgreSql | SqlDialect::Sqlite => '"',
+        };
+
+        result.push(quote);
+        for c in name.chars() {
+            if c == quote {
+                result.push(quote);
+            }
+            result.push(c);
+        }
+        result.push(quote);
+    }
+
+    fn push_string(&self, result: &mut String, s: &str) {
+        result.push('\'');
+        for c in s.chars() {
+            // MySQL treats backslashes as escapes in string literals by default
+            if c == '\'' || (c == '\\' && self.dialect == SqlDialect::MySql) {
+                result.push(c);
+            }
+            result.push(c);
+        }
+        result.push('\'');
+    }
+
+    fn push_value(&self, result: &mut String, cell: &str, column_type: ColumnType) {
+        let cell = cell.trim();
+
+        if cell.is_empty() {
+            result.push_str("NULL");
+            return;
+        }
+
+        match Value::of(cell) {
+            Value::Null => result.push_str("NULL"),
+            Value::Bool(b) if column_type == ColumnType::Boolean => {
+                result.push_str(if b { "TRUE" } else { "FALSE" })
+            }
+            Value::Number(n) if column_type != ColumnType::Text => result.push_str(n),
+            _ => self.push_string(result, cell),
+        }
+    }
+
+    fn column_types(&self, table: &Table, columns: usize) -> Vec<ColumnType> {
+        (0..columns)
+            .map(|index| {
+                let column_type = table
+                    .rows()
+                    .filter_map(|row| row.get(index))
+                    .filter_map(|cell| ColumnType::of(Value::of(cell.trim())))
+                    .reduce(ColumnType::merge)
+                    .unwrap_or(ColumnType::Text);
+
+                // SQLite stores integers too large for 64 bits as REAL, even in
+                // NUMERIC columns, so they're kept as text
+                match (column_type, self.dialect) {
+                    (ColumnType::Decimal, SqlDialect::Sqlite) => ColumnType::Text,
+                    (column_type, _) => column_type,
+                }
+            })
+            .collect()
+    }
+
+    fn add_create_table(&self, result: &mut String, names: &[String], types: &[ColumnType]) {
+        result.push_str("CREATE TABLE ");
+        self.push_identifier(result, &self.table_name);
+        result.push_str(" (\n");
+
+        for (index, (name, column_type)) in names.iter().zip(types.iter()).enumerate() {
+            if index != 0 {
+                result.push_str(",\n");
+            }
+            result.push_str("  ");
+            self.push_identifier(result, name);
+            result.push(' ');
+            result.push_str(column_type.name(self.dialect));
+        }
+
+        result.push_str("\n);\n");
+    }
+
+    fn add_inserts(
+        &self,
+        result: &mut String,
+        table: &Table,
+        names: &[String],
+        types: &[ColumnType],
+    ) {
+        result.push_str("INSERT INTO ");
+        self.push_identifier(result, &self.table_name);
+        result.push_str(" (");
+        for (index, name) in names.iter().enumerate() {
+            if index != 0 {
+                result.push_str(", ");
+            }
+            self.push_identifier(result, name);
+        }
+        result.push_str(") VALUES\n");
+
+        for (index, row) in table.rows().enumerate() {
+            if index != 0 {
+                result.push_str(",\n");
+            }
+            result.push_str("  (");
+            for (index, column_type) in types.iter().copied().enumerate() {
+                if index != 0 {
+                    result.push_str(", ");
+                }
+                let cell = row.get(index).map(|c| c.as_ref()).unwrap_or_default();
+                self.push_value(result, cell, column_type);
+            }
+            result.push(')');
+        }
+
+        result.push_str(";\n");
+    }
+}
+
+impl Formatter for SqlFormatter {
+    fn format(&self, table: Table) -> String {
+        let names = table.column_names();
+        let types = self.column_types(&table, names.len());
+        let mut result = String::new();
+
+        if names.is_empty() {
+            return result;
+        }
+
+        if self.statements == SqlStatements::CreateAndInsert {
+            self.add_create_table(&mut result, &names, &types);
+        }
+
+        if table.rows().next().is_some() {
+            if self.statements == SqlStatements::CreateAndInsert {
+                result.push('\n');
+            }
+            self.add_inserts(&mut result, &table, &names, &types);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Formatter, SqlDialect, SqlFormatter, SqlStatements, Table};
+
+    const TEXT: &str = "id,name,price,ok,code\n\
+                        1,O'Brien,2.5,true,007\n\
+                        3000000000,a\\b,3,FALSE,\n";
+
+    #[test]
+    fn sql_postgresql() {
+        let t = Table::with_text_and_separator(TEXT, ",", true, true);
+
+        assert_eq!(
+            SqlFormatter::new(
+                SqlDialect::PostgreSql,
+                "data",
+                SqlStatements::CreateAndInsert
+            )
+            .format(t),
+            "CREATE TABLE \"data\" (\n  \
+             \"id\" BIGINT,\n  \
+             \"name\" TEXT,\n  \
+             \"price\" DOUBLE PRECISION,\n  \
+             \"ok\" BOOLEAN,\n  \
+             \"code\" TEXT\n\
+             );\n\
+             \n\
+             INSERT INTO \"data\" (\"id\", \"name\", \"price\", \"ok\", \"code\") VALUES\n  \
+             (1, 'O''Brien', 2.5, TRUE, '007'),\n  \
+             (3000000000, 'a\\b', 3, FALSE, NULL);\n"
+        );
+    }
+
+    #[test]
+    fn sql_mysql_inserts_only() {
+        let t = Table::with_text_and_separator("a`b,c\nx\\y,1\n", ",", true, true);
+
+        assert_eq!(
+            SqlFormatter::new(SqlDialect::MySql, "data", SqlStatements::InsertOnly).format(t),
+            "INSERT INTO `data` (`a``b`, `c`) VALUES\n  ('x\\\\y', 1);\n"
+        );
+    }
+
+    #[test]
+    fn sql_mixed_column() {
+        let t = Table::with_text_and_separator("1\nx\n", ",", false, true);
+
+        assert_eq!(
+            SqlFormatter::new(SqlDialect::Sqlite, "t", SqlStatements::CreateAndInsert).format(t),
+            "CREATE TABLE \"t\" (\n  \"column1\" TEXT\n);\n\n\
+             INSERT INTO \"t\" (\"column1\") VALUES\n  ('1'),\n  ('x');\n"
+        );
+    }
+
+    #[test]
+    fn sql_large_integers() {
+        let text = "id,amount\n12345678901234567890,1e3\n1,1.5\n";
+
+        for (dialect, expected) in [
+            (
+                SqlDialect::PostgreSql,
+                "CREATE TABLE \"t\" (\n  \
+                 \"id\" NUMERIC,\n  \
+                 \"amount\" DOUBLE PRECISION\n\
+                 );\n\
+                 \n\
+                 INSERT INTO \"t\" (\"id\", \"amount\") VALUES\n  \
+                 (12345678901234567890, 1e3),\n  \
+                 (1, 1.5);\n",
+            ),
+            (
+                SqlDialect::MySql,
+                "CREATE TABLE `t` (\n  \
+                 `id` DECIMAL(65,0),\n  \
+                 `amount` DOUBLE\n\
+                 );\n\
+                 \n\
+                 INSERT INTO `t` (`id`, `amount`) VALUES\n  \
+                 (12345678901234567890, 1e3),\n  \
+                 (1, 1.5);\n",
+            ),
+            (
+                SqlDialect::Sqlite,
+                "CREATE TABLE \"t\" (\n  \
+                 \"id\" TEXT,\n  \
+                 \"amount\" REAL\n\
+                 );\n\
+                 \n\
+                 INSERT INTO \"t\" (\"id\", \"amount\") VALUES\n  \
+                 ('12345678901234567890', 1e3),\n  \
+                 ('1', 1.5);\n",
+            ),
+        ] {
+            let t = Table::with_text_and_separator(text, ",", true, true);
+
+            assert_eq!(
+                SqlFormatter::new(dialect, "t", SqlStatements::CreateAndInsert).format(t),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn sql_table_name() {
+        let name = String::from("my \"odd\" `table`");
+
+        for (dialect, quoted) in [
+            (SqlDialect::PostgreSql, "\"my \"\"odd\"\" `table`\""),
+            (SqlDialect::MySql, "`my \"odd\" ``table```"),
+            (SqlDialect::Sqlite, "\"my \"\"odd\"\" `table`\""),
+        ] {
+            let t = Table::with_text_and_separator("a\n1\n", ",", true, true);
+            let sql = SqlFormatter::new(dialect, name.clone(), SqlStatements::InsertOnly).format(t);
+
+            assert!(sql.starts_with(&format!("INSERT INTO {quoted} (")), "{sql}");
+        }
+    }
+}
@@ -0,0 +1,366 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{
+    Alignment, is_number, read_html, read_markdown, split_aligned, split_line, split_records,
+};
+use std::borrow::Cow;
+
+/// A table read from text, made of optional titles and of rows of cells.
+///
+/// Rows can have different lengths; formatters pad the shorter ones with
+/// empty cells when the output format needs it.
+pub struct Table<'a> {
+    head: Vec<Cow<'a, str>>,
+    rows: Vec<Vec<Cow<'a, str>>>,
+    alignments: Vec<Alignment>,
+}
+
+impl<'a> Table<'a> {
+    /// Reads cells separated by `separator`, with quoted cells spanning
+    /// multiple lines like CSV. With `has_head`, the first row becomes the
+    /// titles; with `remove_quotes`, the quotes enclosing cells are removed.
+    pub fn with_text_and_separator(
+        text: &'a str,
+        separator: &str,
+        has_head: bool,
+        remove_quotes: bool,
+    ) -> Self {
+        let records = split_records(text, separator);
+        let mut head = Vec::new();
+        let mut rows = Vec::new();
+
+        log::info!("Generating table with separator '{separator}' and titles={has_head}");
+
+        for r in records {
+            if r.is_empty() {
+                continue;
+            }
+
+            let cells = split_line(r, separator, remove_quotes);
+
+            if has_head && head.is_empty() {
+                head = cells.collect();
+            } else {
+                rows.push(cells.collect());
+            }
+        }
+
+        Self {
+            head,
+            rows,
+            alignments: Vec::new(),
+        }
+    }
+
+    /// Reads text whose columns are aligned with spaces, like the output of
+    /// `ps` or `df -h`. With `has_head`, the first row becomes the titles.
+    pub fn with_aligned_text(text: &'a str, has_head: bool) -> Self {
+        log::info!("Generating table from aligned columns and titles={has_head}");
+
+        let mut rows = split_aligned(text, has_head)
+            .into_iter()
+            .map(|r| r.into_iter().map(Cow::Borrowed).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let head = if has_head && !rows.is_empty() {
+            rows.remove(0)
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            head,
+            rows,
+            alignments: Vec::new(),
+        }
+    }
+
+    /// Reads a Markdown pipe table, keeping the alignments of its columns.
+    /// Without `has_head`, its titles (if any) become the first row.
+    pub fn with_markdown_text(text: &'a str, has_head: bool) -> Self {
+        log::info!("Generating table from Markdown and titles={has_head}");
+
+        let mut table = read_markdown(text);
+
+        if !has_head && !table.head.is_empty() {
+            table.rows.insert(0, std::mem::take(&mut table.head));
+        }
+
+        Self {
+            head: table.head,
+            rows: table.rows,
+            alignments: table.alignments,
+        }
+    }
+
+    /// Reads the first `<table>` of an HTML document or fragment. Without
+    /// `has_head`, its titles (if any) become the first row.
+    pub fn with_html_text(text: &'a str, has_head: bool) -> Self {
+        log::info!("Generating table from HTML and titles={has_head}");
+
+        let (mut head, mut rows) = read_html(text);
+
+        if !has_head && !head.is_empty() {
+            rows.insert(0, std::mem::take(&mut head));
+        }
+
+        Self {
+            head,
+            rows,
+            alignments: Vec::new(),
+        }
+    }
+
+    /// Sets the alignment of each column, overriding the ones read from the
+    /// text (like those of a Markdown table). Missing ones are `Auto`.
+    pub fn set_alignments(&mut self, alignments: Vec<Alignment>) {
+        self.alignments = alignments;
+    }
+
+    /// The titles of the columns, if the table has any.
+    pub fn titles(&self) -> Option<&[Cow<'a, str>]> {
+        if self.head.is_empty() {
+            None
+        } else {
+            Some(&self.head)
+        }
+    }
+
+    /// The rows of the table, titles excluded.
+    pub fn rows(&self) -> impl Iterator<Item = &[Cow<'a, str>]> {
+        self.rows.iter().map(|v| v.as_slice())
+    }
+
+    /// Number of columns of the table, that is the length of its longest row.
+    pub fn columns(&self) -> usize {
+        self.rows
+            .iter()
+            .chain(std::iter::once(&self.head))
+            .map(|r| r.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Alignment of each column. `Auto` becomes `Right` for the columns whose
+    /// cells (titles excluded) are all numbers or empty, and stays `Auto` for
+    /// the others.
+    pub fn alignments(&self) -> Vec<Alignment> {
+        (0..self.columns())
+            .map(
+                |index| match self.alignments.get(index).copied().unwrap_or_default() {
+                    Alignment::Auto if self.is_numeric_column(index) => Alignment::Right,
+                    alignment => alignment,
+                },
+            )
+            .collect()
+    }
+
+    fn is_numeric_column(&self, index: usize) -> bool {
+        let mut cells = self
+            .rows
+            .iter()
+            .filter_map(|r| r.get(index))
+            .map(|c| c.trim())
+            .filter(|c| !c.is_empty())
+            .peekable();
+
+        cells.peek().is_some() && cells.all(is_number)
+    }
+
+    /// Names for each column, for formats which key cells by column (like
+    /// JSON objects). Names come from the titles, with empty ones replaced
+    /// by `column<N>` and repeated ones suffixed with `_2`, `_3`...
+    pub fn column_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::with_capacity(self.columns());
+
+        for index in 0..self.columns() {
+            let title = self.head.get(index).map(|t| t.trim()).unwrap_or_default();
+            let base = if title.is_empty() {
+                format!("column{}", index + 1)
+            } else {
+                title.to_string()
+            };
+
+            let mut name = base.clone();
+            let mut suffix = 2;
+            while names.contains(&name) {
+                name = format!("{base}_{suffix}");
+                suffix += 1;
+            }
+
+            names.push(name);
+        }
+
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compare(table: Table<'_>, titles: &[&str], expected: &[&[&str]]) {
+        if let Some(tit) = table.titles() {
+            assert_eq!(tit, titles);
+        }
+
+        let mut exp_idx = 0;
+        for row in table.rows() {
+            assert_eq!(row, expected[exp_idx]);
+            exp_idx += 1;
+        }
+
+        assert_eq!(exp_idx, expected.len());
+    }
+
+    #[test]
+    fn table_happy_case() {
+        let t = Table::with_text_and_separator("t1,t2,t3\na1,a2,a3\nb1,b2,b3", ",", true, true);
+
+        compare(
+            t,
+            &["t1", "t2", "t3"],
+            &[&["a1", "a2", "a3"], &["b1", "b2", "b3"]],
+        );
+    }
+
+    #[test]
+    fn table_happy_case_no_titles() {
+        let t = Table::with_text_and_separator("t1,t2,t3\na1,a2,a3\nb1,b2,b3", ",", false, true);
+
+        compare(
+            t,
+            &[],
+            &[
+                &["t1", "t2", "t3"],
+                &["a1", "a2", "a3"],
+                &["b1", "b2", "b3"],
+            ],
+        );
+    }
+
+    #[test]
+    fn table_empty_field() {
+        let t = Table::with_text_and_separator("t1,t2,t3\na1,,a3\nb1,b2,b3", ",", false, true);
+
+        compare(
+            t,
+            &[],
+            &[&["t1", "t2", "t3"], &["a1", "", "a3"], &["b1", "b2", "b3"]],
+        );
+    }
+
+    #[test]
+    fn table_aligned() {
+        let t = Table::with_aligned_text("NAME   SIZE\nfoo      12\nbarbaz  345\n", true);
+
+        compare(t, &["NAME", "SIZE"], &[&["foo", "12"], &["barbaz", "345"]]);
+    }
+
+    #[test]
+    fn table_markdown() {
+        let t = Table::with_markdown_text("| a | b |\n|---|---|\n| 1 | 2 |\n", true);
+
+        compare(t, &["a", "b"], &[&["1", "2"]]);
+    }
+
+    #[test]
+    fn table_markdown_no_titles() {
+        let t = Table::with_markdown_text("| a | b |\n|---|---|\n| 1 | 2 |\n", false);
+
+        compare(t, &[], &[&["a", "b"], &["1", "2"]]);
+    }
+
+    #[test]
+    fn table_alignments() {
+        let t = Table::with_text_and_separator("a,b,c\n1,x,\n-2.5,y,\n,z,\n", ",", true, true);
+        assert_eq!(
+            t.alignments(),
+            [Alignment::Right, Alignment::Auto, Alignment::Auto]
+        );
+
+        let t = Table::with_markdown_text("| a | b | c |\n|:-:|:--|---|\n| 1 | 2 | 3 |\n", true);
+        assert_eq!(
+            t.alignments(),
+            [Alignment::Center, Alignment::Left, Alignment::Right]
+        );
+    }
+
+    #[test]
+    fn table_set_alignments() {
+        let mut t = Table::with_text_and_separator("a,b,c\n1,x,2\n", ",", true, true);
+        t.set_alignments(vec![Alignment::Center, Alignment::Right]);
+        assert_eq!(
+            t.alignments(),
+            [Alignment::Center, Alignment::Right, Alignment::Right]
+        );
+    }
+
+    #[test]
+    fn table_html() {
+        let t = Table::with_html_text(
+            "<table><tr><th>a</th><th>b</th></tr><tr><td>1</td><td>2</td></tr></table>",
+            true,
+        );
+
+        compare(t, &["a", "b"], &[&["1", "2"]]);
+    }
+
+    #[test]
+    fn table_column_names() {
+        let t =
+            Table::with_text_and_separator("id,,name,name,name_2\n1,2,3,4,5,6\n", ",", true, true);
+
+        assert_eq!(
+            t.column_names(),
+            ["id", "column2", "name", "name_2", "name_2_2", "column6"]
+        );
+    }
+
+    #[test]
+    fn table_multiline_field() {
+        let t = Table::with_text_and_separator(
+            "t1,t2\r\na1,\"first\r\nsecond\"\r\nb1,b2\r\n",
+            ",",
+            true,
+            true,
+        );
+
+        compare(
+            t,
+            &["t1", "t2"],
+            &[&["a1", "first\r\nsecond"], &["b1", "b2"]],
+        );
+    }
+
+    #[test]
+    fn table_crlf_quotes_removed() {
+        let t =
+            Table::with_text_and_separator("\"t1\",\"t2\"\r\n\"a1\",\"a2\"\r\n", ",", true, true);
+
+        compare(t, &["t1", "t2"], &[&["a1", "a2"]]);
+    }
+}
@@ -0,0 +1,284 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Formatter, Table, column_widths, push_padded};
+use std::borrow::Cow;
+
+/// The lines drawn around and between the cells of a plain-text table.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BoxStyle {
+    /// `┌─┬─┐` box-drawing lines
+    Single,
+    /// `╔═╦═╗` box-drawing lines
+    Double,
+    /// `╭─┬─╮` box-drawing lines, with rounded corners
+    Rounded,
+    /// `+-+-+` lines, for places without Unicode
+    Ascii,
+    /// No borders, only a rule under the titles
+    Borderless,
+}
+
+/// A horizontal line, made of the text before the first column, the
+/// character filling the columns, the text between columns and the text
+/// after the last column.
+struct Rule {
+    left: &'static str,
+    fill: char,
+    junction: &'static str,
+    right: &'static str,
+}
+
+struct Borders {
+    top: Option<Rule>,
+    head: Rule,
+    bottom: Option<Rule>,
+    left: &'static str,
+    inner: &'static str,
+    right: &'static str,
+}
+
+impl BoxStyle {
+    const fn borders(self) -> Borders {
+        const fn rule(
+            left: &'static str,
+            fill: char,
+            junction: &'static str,
+            right: &'static str,
+        ) -> Rule {
+            Rule {
+                left,
+                fill,
+                junction,
+                right,
+            }
+        }
+
+        match self {
+            BoxStyle::Single => Borders {
+                top: Some(rule("┌─", '─', "─┬─", "─┐")),
+                head: rule("├─", '─', "─┼─", "─┤"),
+                bottom: Some(rule("└─", '─', "─┴─", "─┘")),
+                left: "│ ",
+                inner: " │ ",
+                right: " │",
+            },
+            BoxStyle::Double => Borders {
+                top: Some(rule("╔═", '═', "═╦═", "═╗")),
+                head: rule("╠═", '═', "═╬═", "═╣"),
+                bottom: Some(rule("╚═", '═', "═╩═", "═╝")),
+                left: "║ ",
+                inner: " ║ ",
+                right: " ║",
+            },
+            BoxStyle::Rounded => Borders {
+                top: Some(rule("╭─", '─', "─┬─", "─╮")),
+                head: rule("├─", '─', "─┼─", "─┤"),
+                bottom: Some(rule("╰─", '─', "─┴─", "─╯")),
+                left: "│ ",
+                inner: " │ ",
+                right: " │",
+            },
+            BoxStyle::Ascii => Borders {
+                top: Some(rule("+-", '-', "-+-", "-+")),
+                head: rule("+=", '=', "=+=", "=+"),
+                bottom: Some(rule("+-", '-', "-+-", "-+")),
+                left: "| ",
+                inner: " | ",
+                right: " |",
+            },
+            BoxStyle::Borderless => Borders {
+                top: None,
+                head: rule("", '─', "  ", ""),
+                bottom: None,
+                left: "",
+                inner: "  ",
+                right: "",
+            },
+        }
+    }
+}
+
+/// Formats tables as plain text with borders, inside a code block so that
+/// chat apps like Slack, which don't render Markdown tables, show them with
+/// a monospace font.
+pub struct TextFormatter {
+    style: BoxStyle,
+}
+
+impl TextFormatter {
+    /// Creates a formatter drawing borders in the given style.
+    pub const fn new(style: BoxStyle) -> Self {
+        Self { style }
+    }
+
+    fn cell_text(cell: &str) -> Cow<'_, str> {
+        cell.trim().into()
+    }
+
+    fn add_rule(result: &mut String, widths: &[usize], rule: &Rule) {
+        result.push_str(rule.left);
+        for (index, width) in widths.iter().copied().enumerate() {
+            if index != 0 {
+                result.push_str(rule.junction);
+            }
+            for _ in 0..width {
+                result.push(rule.fill);
+            }
+        }
+        result.push_str(rule.right);
+        result.push('\n');
+    }
+
+    fn add_cells(result: &mut String, widths: &[usize], row: &[Cow<'_, str>], borders: &Borders) {
+        let cells = (0..widths.len())
+            .map(|index| {
+                row.get(index)
+                    .map(|c| c.trim().lines().map(str::trim).collect::<Vec<_>>())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        let height = cells.iter().map(|c| c.len()).max().unwrap_or(0).max(1);
+
+        for line in 0..height {
+            let mut text = String::from(borders.left);
+            for (index, (cell, width)) in cells.iter().zip(widths.iter().copied()).enumerate() {
+                if index != 0 {
+                    text.push_str(borders.inner);
+                }
+                push_padded(
+                    &mut text,
+                    cell.get(line).copied().unwrap_or_default(),
+                    width,
+                );
+            }
+            text.push_str(borders.right);
+
+            result.push_str(text.trim_end());
+            result.push('\n');
+        }
+    }
+}
+
+impl Formatter for TextFormatter {
+    fn format(&self, table: Table) -> String {
+        let widths = column_widths(&table, Self::cell_text);
+        let borders = self.style.borders();
+
+        let mut result = String::new();
+
+        if widths.is_empty() {
+            return result;
+        }
+
+        result.push_str("```\n");
+
+        if let Some(rule) = &borders.top {
+            Self::add_rule(&mut result, &widths, rule);
+        }
+
+        if let Some(head) = table.titles() {
+            Self::add_cells(&mut result, &widths, head, &borders);
+            Self::add_rule(&mut result, &widths, &borders.head);
+        }
+
+        for row in table.rows() {
+            Self::add_cells(&mut result, &widths, row, &borders);
+        }
+
+        if let Some(rule) = &borders.bottom {
+            Self::add_rule(&mut result, &widths, rule);
+        }
+
+        result.push_str("```\n");
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxStyle, Formatter, Table, TextFormatter};
+
+    fn test(style: BoxStyle, expected: &str) {
+        let t = Table::with_text_and_separator("a,bb\n\"1\n22\",\n", ",", true, true);
+
+        assert_eq!(TextFormatter::new(style).format(t), expected);
+    }
+
+    #[test]
+    fn text_single() {
+        test(
+            BoxStyle::Single,
+            "```\n\
+             ┌────┬────┐\n\
+             │ a  │ bb │\n\
+             ├────┼────┤\n\
+             │ 1  │    │\n\
+             │ 22 │    │\n\
+             └────┴────┘\n\
+             ```\n",
+        );
+    }
+
+    #[test]
+    fn text_ascii() {
+        test(
+            BoxStyle::Ascii,
+            "```\n\
+             +----+----+\n\
+             | a  | bb |\n\
+             +====+====+\n\
+             | 1  |    |\n\
+             | 22 |    |\n\
+             +----+----+\n\
+             ```\n",
+        );
+    }
+
+    #[test]
+    fn text_borderless() {
+        test(
+            BoxStyle::Borderless,
+            "```\n\
+             a   bb\n\
+             ──  ──\n\
+             1\n\
+             22\n\
+             ```\n",
+        );
+    }
+
+    #[test]
+    fn text_no_titles() {
+        let t = Table::with_text_and_separator("a,b\n", ",", false, true);
+
+        assert_eq!(
+            TextFormatter::new(BoxStyle::Rounded).format(t),
+            "```\n╭───┬───╮\n│ a │ b │\n╰───┴───╯\n```\n"
+        );
+    }
+}
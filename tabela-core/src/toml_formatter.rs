@@ -0,0 +1,124 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Formatter, Table, Value, push_quoted};
+
+/// Formats tables as a TOML array of tables named `rows`, keyed by the
+/// titles. TOML has no null, so cells looking like one are left out.
+pub struct TomlFormatter;
+
+impl TomlFormatter {
+    /// Whether a number fits TOML's 64-bit integers and floats.
+    fn fits(n: &str) -> bool {
+        if n.contains(['.', 'e', 'E']) {
+            n.parse::<f64>().is_ok_and(f64::is_finite)
+        } else {
+            n.parse::<i64>().is_ok()
+        }
+    }
+
+    fn push_key(result: &mut String, key: &str) {
+        let is_bare = !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+        if is_bare {
+            result.push_str(key);
+        } else {
+            push_quoted(result, key);
+        }
+    }
+}
+
+impl Formatter for TomlFormatter {
+    fn format(&self, table: Table) -> String {
+        let names = table.column_names();
+        let mut result = String::new();
+
+        for (index, row) in table.rows().enumerate() {
+            if index != 0 {
+                result.push('\n');
+            }
+            result.push_str("[[rows]]\n");
+
+            for (index, name) in names.iter().enumerate() {
+                let value = Value::of(row.get(index).map(|c| c.trim()).unwrap_or_default());
+
+                if value == Value::Null {
+                    continue;
+                }
+
+                Self::push_key(&mut result, name);
+                result.push_str(" = ");
+                match value {
+                    Value::Null => {}
+                    Value::Bool(b) => result.push_str(if b { "true" } else { "false" }),
+                    Value::Number(n) if Self::fits(n) => result.push_str(n),
+                    Value::Number(t) | Value::Text(t) => push_quoted(&mut result, t),
+                }
+                result.push('\n');
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Formatter, Table, TomlFormatter};
+
+    #[test]
+    fn toml_table() {
+        let t = Table::with_text_and_separator(
+            "id,full name,ok\n1,\"a \"\"b\"\"\",false\n2,,NULL\n",
+            ",",
+            true,
+            true,
+        );
+
+        assert_eq!(
+            TomlFormatter.format(t),
+            "[[rows]]\nid = 1\n\"full name\" = \"a \\\"b\\\"\"\nok = false\n\n\
+             [[rows]]\nid = 2\n\"full name\" = \"\"\n"
+        );
+    }
+
+    #[test]
+    fn toml_large_numbers() {
+        let t = Table::with_text_and_separator(
+            "a,b,c\n9223372036854775807,9223372036854775808,1e999\n",
+            ",",
+            true,
+            true,
+        );
+
+        assert_eq!(
+            TomlFormatter.format(t),
+            "[[rows]]\na = 9223372036854775807\nb = \"9223372036854775808\"\nc = \"1e999\"\n"
+        );
+    }
+}
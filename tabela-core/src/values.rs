@@ -0,0 +1,111 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::is_number;
+
+/// The value a cell looks like, for formats with typed values (like JSON).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value<'a> {
+    Null,
+    Bool(bool),
+    /// A number written the way JSON writes them: no leading `+` or zeros, and
+    /// digits on both sides of the decimal point
+    Number(&'a str),
+    Text(&'a str),
+}
+
+impl<'a> Value<'a> {
+    /// Guesses the type of a trimmed cell. Numbers with a leading `+`, leading
+    /// zeros (like ZIP codes) or a bare decimal point stay text, as they
+    /// couldn't be written back the same way.
+    pub fn of(cell: &'a str) -> Self {
+        if cell.eq_ignore_ascii_case("null") {
+            Value::Null
+        } else if cell.eq_ignore_ascii_case("true") {
+            Value::Bool(true)
+        } else if cell.eq_ignore_ascii_case("false") {
+            Value::Bool(false)
+        } else if is_plain_number(cell) {
+            Value::Number(cell)
+        } else {
+            Value::Text(cell)
+        }
+    }
+}
+
+/// Appends a double-quoted string with JSON escapes, which YAML and TOML
+/// double-quoted strings accept too.
+pub fn push_quoted(result: &mut String, s: &str) {
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 || c == '\u{7f}' => {
+                result.push_str(&format!("\\u{:04x}", c as u32))
+            }
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+}
+
+fn is_plain_number(s: &str) -> bool {
+    if !is_number(s) {
+        return false;
+    }
+
+    let unsigned = s.strip_prefix('-').unwrap_or(s);
+    let mantissa = unsigned.split(['e', 'E']).next().unwrap_or_default();
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, "1"));
+
+    !unsigned.starts_with('+')
+        && !integer.is_empty()
+        && !fraction.is_empty()
+        && (integer == "0" || !integer.starts_with('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+
+    #[test]
+    fn values() {
+        assert_eq!(Value::of("NULL"), Value::Null);
+        assert_eq!(Value::of("TRUE"), Value::Bool(true));
+        assert_eq!(Value::of("false"), Value::Bool(false));
+
+        for n in ["0", "42", "-3", "0.5", "-1.5E-3"] {
+            assert_eq!(Value::of(n), Value::Number(n));
+        }
+
+        for t in ["", "+3", "007", ".5", "5.", "1,5", "yes"] {
+            assert_eq!(Value::of(t), Value::Text(t));
+        }
+    }
+}
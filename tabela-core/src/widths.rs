@@ -0,0 +1,106 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::Table;
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Width of a line of text once displayed with a monospace font, in columns.
+///
+/// East Asian wide characters and emoji take two columns, combining marks
+/// none. Every grapheme cluster is drawn as one glyph, so emoji joined into
+/// one (like families or flags) take two columns too.
+pub fn text_width(s: &str) -> usize {
+    s.graphemes(true).map(|g| g.width().min(2)).sum()
+}
+
+/// Updates the widths of the columns with a row, given how each cell is going
+/// to be rendered. Cells spanning multiple lines are as wide as their longest line.
+fn precalc_widths(
+    widths: &mut Vec<usize>,
+    row: &[Cow<'_, str>],
+    cell_text: impl Fn(&str) -> Cow<'_, str>,
+) {
+    for (idx, cell) in row.iter().map(|c| cell_text(c)).enumerate() {
+        let cell_len = cell.lines().map(text_width).max().unwrap_or(0);
+
+        #[allow(clippy::comparison_chain)]
+        if idx == widths.len() {
+            widths.push(cell_len);
+        } else if idx < widths.len() {
+            widths[idx] = std::cmp::max(widths[idx], cell_len);
+        } else {
+            log::error!("Width index out of range");
+        }
+    }
+}
+
+/// Computes the widths of all the columns of a table, titles included.
+pub fn column_widths(table: &Table, cell_text: impl Fn(&str) -> Cow<'_, str>) -> Vec<usize> {
+    let mut widths = Vec::new();
+
+    if let Some(head) = table.titles() {
+        precalc_widths(&mut widths, head, &cell_text);
+    }
+
+    for row in table.rows() {
+        precalc_widths(&mut widths, row, &cell_text);
+    }
+
+    widths
+}
+
+/// Appends a text followed by enough spaces to fill `width`.
+pub fn push_padded(result: &mut String, s: &str, width: usize) {
+    result.push_str(s);
+
+    for _ in text_width(s)..width {
+        result.push(' ');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{push_padded, text_width};
+
+    #[test]
+    fn widths() {
+        assert_eq!(text_width("città"), 5);
+        assert_eq!(text_width("citta\u{300}"), 5);
+        assert_eq!(text_width("東京"), 4);
+        assert_eq!(text_width("ｶﾀｶﾅ"), 4);
+        assert_eq!(text_width("🍕!"), 3);
+        assert_eq!(text_width("👨\u{200d}👩\u{200d}👧"), 2);
+        assert_eq!(text_width("🇮🇹"), 2);
+    }
+
+    #[test]
+    fn padding() {
+        let mut s = String::new();
+        push_padded(&mut s, "東京", 6);
+        assert_eq!(s, "東京  ");
+    }
+}
@@ -0,0 +1,166 @@
+/* MIT License
+ *
+ * Copyright (c) 2025 Marco Mastropaolo
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * SPDX-License-Identifier: MIT
+ */
+
+use super::{Formatter, Table, Value, is_number, push_quoted};
+
+/// Formats tables as a YAML list of mappings, keyed by the titles. Strings are
+/// only quoted when YAML would otherwise read them as something else.
+pub struct YamlFormatter;
+
+impl YamlFormatter {
+    fn needs_quotes(s: &str) -> bool {
+        // Also the YAML 1.1 booleans and null, still common among parsers
+        const RESERVED: &[&str] = &[
+            "null", "true", "false", "~", "y", "n", "yes", "no", "on", "off",
+        ];
+
+        s.is_empty()
+            || RESERVED.iter().any(|r| s.eq_ignore_ascii_case(r))
+            || is_number(s)
+            || Self::is_number_like(s)
+            || s.starts_with([
+                '-', '?', ':', ',', '[', ']', '{', '}', '#', '&', '*', '!', '|', '>', '\'', '"',
+                '%', '@', '`', ' ',
+            ])
+            || s.ends_with([' ', ':'])
+            || s.contains(": ")
+            || s.contains(" #")
+            || s.chars().any(|c| c.is_control())
+    }
+
+    /// Whether a plain scalar could be read as a number or a timestamp by the
+    /// YAML 1.1 or 1.2 schemas, like `0x10`, `0o17`, `1_000`, `1:30`, `.inf`
+    /// or `2024-01-01T10:00:00Z`. Errs on the side of quoting.
+    fn is_number_like(s: &str) -> bool {
+        let s = s.strip_prefix(['-', '+']).unwrap_or(s).to_ascii_lowercase();
+
+        if s == ".inf" || s == ".nan" {
+            return true;
+        }
+
+        let digits = s.strip_prefix('.').unwrap_or(&s);
+
+        digits.starts_with(|c: char| c.is_ascii_digit())
+            && s.chars()
+                .all(|c| c.is_ascii_hexdigit() || "xobtz_.:+- ".contains(c))
+    }
+
+    fn push_string(result: &mut String, s: &str) {
+        if Self::needs_quotes(s) {
+            push_quoted(result, s);
+        } else {
+            result.push_str(s);
+        }
+    }
+
+    fn push_value(result: &mut String, cell: &str) {
+        match Value::of(cell.trim()) {
+            Value::Null => result.push_str("null"),
+            Value::Bool(b) => result.push_str(if b { "true" } else { "false" }),
+            Value::Number(n) => result.push_str(n),
+            Value::Text(t) => Self::push_string(result, t),
+        }
+    }
+}
+
+impl Formatter for YamlFormatter {
+    fn format(&self, table: Table) -> String {
+        let names = table.column_names();
+        let mut result = String::new();
+
+        if names.is_empty() || table.rows().next().is_none() {
+            result.push_str("[]\n");
+            return result;
+        }
+
+        for row in table.rows() {
+            for (index, name) in names.iter().enumerate() {
+                result.push_str(if index == 0 { "- " } else { "  " });
+                Self::push_string(&mut result, name);
+                result.push_str(": ");
+                Self::push_value(
+                    &mut result,
+                    row.get(index).map(|c| c.as_ref()).unwrap_or_default(),
+                );
+                result.push('\n');
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Formatter, Table, YamlFormatter};
+
+    #[test]
+    fn yaml_quotes() {
+        for s in [
+            "", "no", "ON", "~", "007", "+3", "- x", "a: b", "a #b", "x:", "a\nb", " x",
+        ] {
+            assert!(YamlFormatter::needs_quotes(s), "{s}");
+        }
+
+        for s in [
+            "2024-01-01",
+            "2024-01-01T10:00:00Z",
+            "2024-01-01 10:00:00.5 +01:00",
+            "1:30",
+            "-1:30:00",
+            "0x10",
+            "0o17",
+            "0b101",
+            "1_000",
+            "1_000.5",
+            ".inf",
+            "-.INF",
+            ".NaN",
+        ] {
+            assert!(YamlFormatter::needs_quotes(s), "{s}");
+        }
+
+        for s in [
+            "abc", "a:b", "a#b", "2 hours", "C++", "it's", "v1.2", ".git",
+        ] {
+            assert!(!YamlFormatter::needs_quotes(s), "{s}");
+        }
+    }
+
+    #[test]
+    fn yaml_table() {
+        let t = Table::with_text_and_separator(
+            "id,name,active\n1,foo,TRUE\n2,\"007\",null\n",
+            ",",
+            true,
+            true,
+        );
+
+        assert_eq!(
+            YamlFormatter.format(t),
+            "- id: 1\n  name: foo\n  active: true\n- id: 2\n  name: \"007\"\n  active: null\n"
+        );
+    }
+}